@@ -0,0 +1,245 @@
+//! Compiles `src/de-oger.json` and the other profiles into static perfect hash tables, so the
+//! built-in dictionaries don't need to be parsed at runtime.
+
+use std::collections::HashSet;
+use std::env;
+use std::fmt::Write as _;
+use std::fs;
+use std::path::Path;
+
+#[path = "src/dictionary/raw.rs"]
+#[allow(dead_code)]
+mod raw;
+
+use raw::{RawDictionary, RawEnding, RawFillers, RawRule};
+
+/// Name, static and data file of every built-in profile, the first one is the default.
+const PROFILES: [(&str, &str, &str); 4] = [
+    ("oger", "BUILTIN", "src/de-oger.json"),
+    ("franconian", "FRANCONIAN", "src/profiles/franconian.json"),
+    ("swabian", "SWABIAN", "src/profiles/swabian.json"),
+    ("bavarian", "BAVARIAN", "src/profiles/bavarian.json"),
+];
+
+fn main() {
+    println!("cargo:rerun-if-changed=src/dictionary/raw.rs");
+
+    let mut code = String::new();
+    let mut registry = Vec::new();
+    for (profile, name, path) in PROFILES.iter() {
+        println!("cargo:rerun-if-changed={}", path);
+
+        let json = fs::read_to_string(path).expect("Could not read translation.");
+        let dictionary: RawDictionary = serde_json::from_str(&json).expect("Could not parse translation.");
+        // older layouts are only migrated at runtime, the shipped files have to be current.
+        assert_eq!(dictionary.version, Some(2), "{} must use version 2 of the dictionary format.", path);
+
+        code.push_str(&generate(name, &dictionary));
+        registry.push(format!("({:?}, &{})", profile, name));
+    }
+    writeln!(
+        code,
+        "static PROFILES: [(&str, &Dictionary); {}] = [{}];",
+        registry.len(),
+        registry.join(", ")
+    )
+    .unwrap();
+
+    let out = Path::new(&env::var("OUT_DIR").unwrap()).join("builtin.rs");
+    fs::write(out, code).expect("Could not write built-in dictionaries.");
+
+    #[cfg(feature = "ffi")]
+    generate_header();
+}
+
+/// Generates the C header of the `ffi` module, the `ffi` test compares it with
+/// `include/meddl_translate.h`.
+#[cfg(feature = "ffi")]
+fn generate_header() {
+    println!("cargo:rerun-if-changed=src/ffi.rs");
+
+    let config = cbindgen::Config {
+        language: cbindgen::Language::C,
+        header: Some(String::from(
+            "/* C API of meddl_translate, generated from src/ffi.rs.\n *\n * Build the library with `cargo build --release --package meddl_translate_ffi` and\n * link against it with `-lmeddl_translate_ffi`. */",
+        )),
+        include_guard: Some(String::from("MEDDL_TRANSLATE_H")),
+        cpp_compat: true,
+        documentation_style: cbindgen::DocumentationStyle::C99,
+        ..cbindgen::Config::default()
+    };
+    let out = Path::new(&env::var("OUT_DIR").unwrap()).join("meddl_translate.h");
+
+    cbindgen::Builder::new()
+        .with_config(config)
+        .with_src("src/ffi.rs")
+        .generate()
+        .expect("Could not generate the C header.")
+        .write_to_file(out);
+}
+
+/// Generates a `static <name>: Dictionary` and all the tables it points to.
+fn generate(name: &str, dictionary: &RawDictionary) -> String {
+    let mut code = String::new();
+
+    let mut translations = phf_codegen::Map::new();
+    let mut words: Vec<&String> = dictionary.translations.keys().collect();
+    words.sort();
+    for (index, word) in words.into_iter().enumerate() {
+        let pool = format!("{}_TRANSLATION_{}", name, index);
+        write_pool(&mut code, &pool, &dictionary.translations[word]);
+        translations.entry(word.as_str(), &format!("Cow::Borrowed(&{})", pool));
+    }
+    writeln!(
+        code,
+        "static {}_TRANSLATIONS: phf::Map<&'static str, Pool> = {};",
+        name,
+        translations.build()
+    )
+    .unwrap();
+
+    // plain words go into the hash table, the same way `Ignored::extend` sorts them.
+    let (patterns, words): (Vec<String>, Vec<String>) = dictionary
+        .ignored
+        .iter()
+        .cloned()
+        .partition(|entry| raw::is_ignored_pattern(entry));
+    let mut ignored = phf_codegen::Map::new();
+    let mut seen = HashSet::new();
+    for word in words {
+        let word = if dictionary.ignore_case { word.to_lowercase() } else { word };
+        if seen.insert(word.clone()) {
+            ignored.entry(word, "()");
+        }
+    }
+    writeln!(code, "static {}_IGNORED: phf::Map<&'static str, ()> = {};", name, ignored.build()).unwrap();
+    write_pool(&mut code, &format!("{}_IGNORED_PATTERNS", name), &patterns);
+
+    write_pool(&mut code, &format!("{}_QUESTION_MARK", name), &dictionary.question_mark);
+    write_pool(&mut code, &format!("{}_EXCLAMATION_MARK", name), &dictionary.exclamation_mark);
+    write_pool(&mut code, &format!("{}_DOT", name), &dictionary.dot);
+    write_pairs(&mut code, &format!("{}_TWISTED_CHARS", name), &dictionary.twisted_chars);
+    write_pairs(&mut code, &format!("{}_TWIST_BEGINNING", name), &dictionary.twist_beginning);
+    write_endings(&mut code, &format!("{}_EN", name), &dictionary.en);
+    write_rules(&mut code, &format!("{}_RULES", name), &dictionary.rules);
+    write_pool(&mut code, &format!("{}_NUMBERS", name), &dictionary.numbers);
+
+    let mut pronunciations = phf_codegen::Map::new();
+    for (word, ipa) in &dictionary.pronunciations {
+        pronunciations.entry(word.as_str(), &text(ipa));
+    }
+    writeln!(
+        code,
+        "static {}_PRONUNCIATIONS: phf::Map<&'static str, Text> = {};",
+        name,
+        pronunciations.build()
+    )
+    .unwrap();
+    let openers = write_fillers(&mut code, &format!("{}_OPENERS", name), &dictionary.openers);
+    let interjections = write_fillers(&mut code, &format!("{}_INTERJECTIONS", name), &dictionary.interjections);
+    let closers = write_fillers(&mut code, &format!("{}_CLOSERS", name), &dictionary.closers);
+
+    writeln!(
+        code,
+        "static {name}: Dictionary = Dictionary {{
+    translations: Table::Static(&{name}_TRANSLATIONS),
+    longest_phrase: {longest_phrase},
+    ignored: Ignored::new(Table::Static(&{name}_IGNORED), Cow::Borrowed(&{name}_IGNORED_PATTERNS), {ignore_case}),
+    question_mark: Cow::Borrowed(&{name}_QUESTION_MARK),
+    exclamation_mark: Cow::Borrowed(&{name}_EXCLAMATION_MARK),
+    dot: Cow::Borrowed(&{name}_DOT),
+    twisted_chars: Cow::Borrowed(&{name}_TWISTED_CHARS),
+    twist_beginning: Cow::Borrowed(&{name}_TWIST_BEGINNING),
+    en: Cow::Borrowed(&{name}_EN),
+    quotation_mark: {quotation_mark},
+    interlude: {interlude},
+    rules: Rules::new(Cow::Borrowed(&{name}_RULES)),
+    openers: {openers},
+    interjections: {interjections},
+    closers: {closers},
+    numbers: Cow::Borrowed(&{name}_NUMBERS),
+    pronunciations: Table::Static(&{name}_PRONUNCIATIONS),
+    transliterations: OnceLock::new(),
+    replacements: OnceLock::new(),
+}};",
+        name = name,
+        longest_phrase = dictionary.longest_phrase(),
+        ignore_case = dictionary.ignore_case,
+        quotation_mark = text(dictionary.quotation_mark.as_deref().unwrap_or("\"")),
+        interlude = text(&dictionary.interlude),
+        openers = openers,
+        interjections = interjections,
+        closers = closers,
+    )
+    .unwrap();
+
+    code
+}
+
+fn text(string: &str) -> String {
+    format!("Cow::Borrowed({:?})", string)
+}
+
+fn write_pool(code: &mut String, name: &str, pool: &[String]) {
+    let texts: Vec<String> = pool.iter().map(|string| text(string)).collect();
+
+    writeln!(code, "static {}: [Text; {}] = [{}];", name, texts.len(), texts.join(", ")).unwrap();
+}
+
+fn write_pairs(code: &mut String, name: &str, pairs: &[(String, String)]) {
+    let pairs: Vec<String> = pairs
+        .iter()
+        .map(|(from, to)| format!("({}, {})", text(from), text(to)))
+        .collect();
+
+    writeln!(code, "static {}: [(Text, Text); {}] = [{}];", name, pairs.len(), pairs.join(", ")).unwrap();
+}
+
+/// Writes the phrases of `fillers` and returns the expression creating the `Fillers`.
+fn write_fillers(code: &mut String, name: &str, fillers: &RawFillers) -> String {
+    assert!((0.0..=1.0).contains(&fillers.probability), "{} has a probability outside of 0 to 1.", name);
+    write_pool(code, name, &fillers.phrases);
+
+    format!("Fillers::new({:?}, Cow::Borrowed(&{}))", fillers.probability, name)
+}
+
+fn write_endings(code: &mut String, name: &str, endings: &[RawEnding]) {
+    let endings: Vec<String> = endings
+        .iter()
+        .map(|ending| {
+            let except: Vec<String> = ending.except.iter().map(|word| text(&word.to_lowercase())).collect();
+
+            format!(
+                "Ending {{ suffix: {}, replacement: {}, min_stem: {}, except: Cow::Borrowed(&[{}]) }}",
+                text(&ending.suffix),
+                text(&ending.replacement),
+                ending.min_stem,
+                except.join(", ")
+            )
+        })
+        .collect();
+
+    writeln!(code, "static {}: [Ending; {}] = [{}];", name, endings.len(), endings.join(", ")).unwrap();
+}
+
+fn write_rules(code: &mut String, name: &str, rules: &[RawRule]) {
+    let rules: Vec<String> = rules
+        .iter()
+        .map(|rule| {
+            let conditions: Vec<String> = rule
+                .conditions
+                .iter()
+                .map(|condition| format!("Condition::{:?}", condition))
+                .collect();
+
+            format!(
+                "RuleSource {{ pattern: {}, replacement: {}, conditions: Cow::Borrowed(&[{}]) }}",
+                text(&rule.pattern),
+                text(&rule.replacement),
+                conditions.join(", ")
+            )
+        })
+        .collect();
+
+    writeln!(code, "static {}: [RuleSource; {}] = [{}];", name, rules.len(), rules.join(", ")).unwrap();
+}
@@ -0,0 +1,8 @@
+use std::io;
+
+fn main() -> io::Result<()> {
+    let stdin = io::stdin();
+    let stdout = io::stdout();
+
+    meddl_translate::stream::translate_stream(stdin.lock(), stdout.lock())
+}
@@ -0,0 +1,6 @@
+//! The C library of `meddl_translate`, exporting the functions of `meddl_translate::ffi`.
+//!
+//! `cargo build --release -p meddl_translate_ffi` builds `libmeddl_translate_ffi`, the
+//! functions are declared in `include/meddl_translate.h` of the main crate.
+
+pub use meddl_translate::ffi::*;
@@ -0,0 +1,78 @@
+/* C API of meddl_translate, generated from src/ffi.rs.
+ *
+ * Build the library with `cargo build --release --package meddl_translate_ffi` and
+ * link against it with `-lmeddl_translate_ffi`. */
+
+#ifndef MEDDL_TRANSLATE_H
+#define MEDDL_TRANSLATE_H
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+// A translator owned by C code, created by one of the `meddl_translator_*` functions and
+// released with [`meddl_translator_free`].
+typedef struct MeddlTranslator MeddlTranslator;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+// Creates a translator using the built-in dictionary.
+struct MeddlTranslator *meddl_translator_new(void);
+
+// Creates a translator using the built-in dialect profile called `name`, `NULL` if there is no
+// such profile.
+//
+// # Safety
+//
+// `name` has to be `NULL` or point to a NUL-terminated string.
+struct MeddlTranslator *meddl_translator_with_profile(const char *name);
+
+// Creates a translator using the dictionary `json`, `NULL` if it isn't a valid dictionary.
+//
+// # Safety
+//
+// `json` has to be `NULL` or point to a NUL-terminated string.
+struct MeddlTranslator *meddl_translator_with_dictionary(const char *json);
+
+// Makes every translation of `translator` deterministic, `false` if `translator` is `NULL`.
+//
+// # Safety
+//
+// `translator` has to be `NULL` or a translator that hasn't been freed.
+bool meddl_translator_set_seed(struct MeddlTranslator *translator, uint64_t seed);
+
+// Translates `text`, `NULL` if an argument is `NULL` or `text` isn't valid UTF-8. The result
+// has to be released with [`meddl_string_free`].
+//
+// # Safety
+//
+// `translator` has to be `NULL` or a translator that hasn't been freed, `text` has to be
+// `NULL` or point to a NUL-terminated string.
+char *meddl_translate(const struct MeddlTranslator *translator, const char *text);
+
+// The message of the last error on this thread, `NULL` if nothing failed yet. The message
+// belongs to the library and stays valid until the next error on this thread.
+const char *meddl_last_error(void);
+
+// Releases a string returned by [`meddl_translate`], `NULL` is ignored.
+//
+// # Safety
+//
+// `string` has to be `NULL` or a string returned by this library that hasn't been freed.
+void meddl_string_free(char *string);
+
+// Releases a translator, `NULL` is ignored.
+//
+// # Safety
+//
+// `translator` has to be `NULL` or a translator that hasn't been freed.
+void meddl_translator_free(struct MeddlTranslator *translator);
+
+#ifdef __cplusplus
+}  // extern "C"
+#endif  // __cplusplus
+
+#endif  /* MEDDL_TRANSLATE_H */
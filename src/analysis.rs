@@ -0,0 +1,262 @@
+//! Scores how strongly a text is already written in dialect.
+//!
+//! Each word counts as dialect if it is a replacement form from the translations, contains a
+//! twisted-char pattern like "gg", "schd" or "schb", or is part of a suffix phrase like
+//! "hätt i gsachd". The score is the share of such words, between 0 and 1.
+//!
+//! # Example
+//!
+//! ```
+//! use meddl_translate::analysis::{analyze, EvidenceKind};
+//!
+//! let analysis = analyze("Meddl Loide, des bascht scho!");
+//!
+//! assert_eq!(analysis.score, 1.0);
+//! assert_eq!(analysis.evidence[0].kind, EvidenceKind::Replacement);
+//! assert_eq!(analysis.evidence[2].text, "des bascht scho");
+//! assert_eq!(analyze("Hallo Leute").score, 0.0);
+//! ```
+
+use std::ops::Range;
+
+use crate::Dictionary;
+
+/// What made a word count as dialect.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EvidenceKind {
+    /// A word that only appears in the translations, like "Loide".
+    Replacement,
+    /// A word containing the twisted form of a `twistedChars` or `twistBeginning` pair.
+    TwistedChars,
+    /// A phrase from the `questionMark`, `exclamationMark` or `dot` pools.
+    Suffix,
+}
+
+/// A match found in the analysed text.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Evidence {
+    /// Byte range in the analysed text.
+    pub span: Range<usize>,
+    /// The matched text.
+    pub text: String,
+    /// The dictionary entry that matched: the lowercase word, the twisted pattern or the phrase.
+    pub matched: String,
+    pub kind: EvidenceKind,
+}
+
+/// The result of [`analyze`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Analysis {
+    /// Share of words in dialect, from 0 for none to 1 for all of them.
+    pub score: f64,
+    /// The matches in the order they appear in the text.
+    pub evidence: Vec<Evidence>,
+}
+
+/// Analyses `text` using the built-in dictionary.
+pub fn analyze(text: &str) -> Analysis {
+    analyze_with(text, Dictionary::builtin())
+}
+
+/// Analyses `text` using the translations, twisted chars and suffixes of `dictionary`.
+///
+/// # Example
+///
+/// ```
+/// use meddl_translate::analysis::analyze_with;
+/// use meddl_translate::Dictionary;
+///
+/// let dictionary = Dictionary::from_json(r#"{"translations": {"Hallo": ["Servus"]}}"#).unwrap();
+///
+/// assert_eq!(analyze_with("Servus Leute", &dictionary).score, 0.5);
+/// ```
+pub fn analyze_with(text: &str, dictionary: &Dictionary) -> Analysis {
+    let words = words(text);
+    let lowercase: Vec<String> = words.iter().map(|span| text[span.clone()].to_lowercase()).collect();
+    let mut covered = vec![false; words.len()];
+    let mut evidence = Vec::new();
+
+    for phrase in suffix_phrases(dictionary) {
+        let phrase_words: Vec<String> = words_of(&phrase);
+        if phrase_words.is_empty() {
+            continue;
+        }
+
+        let mut index = 0;
+        while index + phrase_words.len() <= words.len() {
+            let range = index..index + phrase_words.len();
+            if lowercase[range.clone()] == phrase_words[..] && !covered[range.clone()].contains(&true) {
+                let span = words[range.start].start..words[range.end - 1].end;
+                evidence.push(Evidence {
+                    text: String::from(&text[span.clone()]),
+                    span,
+                    matched: phrase.clone(),
+                    kind: EvidenceKind::Suffix,
+                });
+                covered[range.clone()].iter_mut().for_each(|covered| *covered = true);
+                index = range.end;
+            } else {
+                index += 1;
+            }
+        }
+    }
+
+    let patterns = twisted_patterns(dictionary);
+    for (index, span) in words.iter().enumerate() {
+        if covered[index] {
+            continue;
+        }
+        let word = &lowercase[index];
+        let (kind, matched) = if dictionary.is_replacement(word) {
+            (EvidenceKind::Replacement, word.clone())
+        } else if is_german(&text[span.clone()], word, dictionary) {
+            continue;
+        } else if let Some(pattern) = patterns.iter().find(|pattern| pattern.matches(word)) {
+            (EvidenceKind::TwistedChars, pattern.twisted.clone())
+        } else {
+            continue;
+        };
+
+        evidence.push(Evidence {
+            span: span.clone(),
+            text: String::from(&text[span.clone()]),
+            matched,
+            kind,
+        });
+        covered[index] = true;
+    }
+    evidence.sort_by_key(|evidence| evidence.span.start);
+
+    let score = match words.len() {
+        0 => 0.0,
+        total => covered.iter().filter(|covered| **covered).count() as f64 / total as f64,
+    };
+
+    Analysis { score, evidence }
+}
+
+/// A twisted form to look for, either anywhere in a word or only at its beginning.
+struct Pattern {
+    twisted: String,
+    beginning: bool,
+}
+
+impl Pattern {
+    fn matches(&self, word: &str) -> bool {
+        match self.beginning {
+            true => word.starts_with(&self.twisted),
+            false => word.contains(&self.twisted),
+        }
+    }
+}
+
+/// The twisted forms that don't appear in German words, single letters like the "d" of
+/// "t" → "d" would match almost everything.
+fn twisted_patterns(dictionary: &Dictionary) -> Vec<Pattern> {
+    let pattern = |(original, twisted): &(_, _), beginning: bool| {
+        let original = String::from(AsRef::<str>::as_ref(original)).to_lowercase();
+        let twisted = String::from(AsRef::<str>::as_ref(twisted)).to_lowercase();
+        let is_word = |text: &str| !text.is_empty() && text.chars().all(char::is_alphabetic);
+
+        match is_word(&original) && is_word(&twisted) && twisted.chars().count() > 1 && original != twisted {
+            true => Some(Pattern { twisted, beginning }),
+            false => None,
+        }
+    };
+
+    let mut patterns: Vec<Pattern> = dictionary
+        .twist_beginning
+        .iter()
+        .filter_map(|pair| pattern(pair, true))
+        .chain(dictionary.twisted_chars.iter().filter_map(|pair| pattern(pair, false)))
+        .collect();
+    patterns.sort_by_key(|pattern| std::cmp::Reverse(pattern.twisted.len()));
+
+    patterns
+}
+
+/// The phrases of the suffix pools without their punctuation, longest first.
+fn suffix_phrases(dictionary: &Dictionary) -> Vec<String> {
+    let mut phrases: Vec<String> = dictionary
+        .question_mark
+        .iter()
+        .chain(dictionary.exclamation_mark.iter())
+        .chain(dictionary.dot.iter())
+        .map(|suffix| words_of(suffix).join(" "))
+        .filter(|phrase| !phrase.is_empty())
+        .collect();
+    phrases.sort_by(|a, b| b.split(' ').count().cmp(&a.split(' ').count()).then_with(|| a.cmp(b)));
+    phrases.dedup();
+
+    phrases
+}
+
+/// Whether `word` is translated or ignored by `dictionary`, so it isn't dialect.
+fn is_german(word: &str, lowercase: &str, dictionary: &Dictionary) -> bool {
+    dictionary.translation(word).is_some() || dictionary.translation(lowercase).is_some() || dictionary.is_ignored(word)
+}
+
+/// Byte ranges of the words in `text`, runs of letters.
+fn words(text: &str) -> Vec<Range<usize>> {
+    let mut words = Vec::new();
+    let mut start = None;
+
+    for (index, c) in text.char_indices() {
+        match (c.is_alphabetic(), start) {
+            (true, None) => start = Some(index),
+            (false, Some(word_start)) => {
+                words.push(word_start..index);
+                start = None;
+            }
+            _ => {}
+        }
+    }
+    if let Some(start) = start {
+        words.push(start..text.len());
+    }
+
+    words
+}
+
+fn words_of(text: &str) -> Vec<String> {
+    words(text).into_iter().map(|span| text[span].to_lowercase()).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    mod analyze {
+        use crate::analysis::{analyze, analyze_with, EvidenceKind};
+        use crate::Dictionary;
+
+        #[test]
+        fn should_find_twisted_chars() {
+            let analysis = analyze("Schbägg und Leute");
+
+            assert_eq!(analysis.evidence.len(), 1);
+            assert_eq!(analysis.evidence[0].kind, EvidenceKind::TwistedChars);
+            assert_eq!(analysis.evidence[0].matched, "schb");
+            assert_eq!(analysis.evidence[0].span, 0..8);
+        }
+
+        #[test]
+        fn should_prefer_suffix_phrases() {
+            let dictionary = Dictionary::from_json(
+                r#"{"dot": [" hätt i gsachd."], "twistedChars": [["t", "dd"]]}"#,
+            )
+            .unwrap();
+            let analysis = analyze_with("Des hätt i gsachd. Sadd", &dictionary);
+
+            assert_eq!(analysis.evidence.len(), 2);
+            assert_eq!(analysis.evidence[0].kind, EvidenceKind::Suffix);
+            assert_eq!(analysis.evidence[0].text, "hätt i gsachd");
+            assert_eq!(analysis.evidence[1].kind, EvidenceKind::TwistedChars);
+            assert_eq!(analysis.score, 0.8);
+        }
+
+        #[test]
+        fn should_not_count_german_words() {
+            assert_eq!(analyze("").score, 0.0);
+            assert!(analyze("Der Hund liegt im Garten").evidence.is_empty());
+        }
+    }
+}
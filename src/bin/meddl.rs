@@ -0,0 +1,277 @@
+//! Command line interface for meddl_translate.
+
+use std::env;
+use std::fs;
+use std::io::{self, Write};
+use std::path::Path;
+use std::process::ExitCode;
+
+use meddl_translate::analysis::EvidenceKind;
+use meddl_translate::{stream, Dictionary, Layer, LayeredDictionary, Severity, Translator};
+
+const USAGE: &str = "Usage:
+    meddl [translate] [--profile NAME] [--fillers] [--numbers] [--transliteration] [--ssml] [TEXT...]
+                                   translate TEXT, or stdin if no TEXT is given,
+                                   --fillers inserts openers, interjections and closers,
+                                   --numbers writes whole numbers in words,
+                                   --transliteration also finds words written with ae, oe, ue and ss,
+                                   --ssml prints SSML for text-to-speech engines
+    meddl score [TEXT...]          score how strongly TEXT, or stdin, is already in dialect and
+                                   list the evidence
+    meddl stats [--profile NAME] [--json] [--limit N] [FILE...]
+                                   report the dictionary coverage of FILEs, or stdin, as a table
+                                   listing N words of each kind (20 by default), or as JSON
+    meddl suggest [--min-count N] DICTIONARY [FILE...]
+                                   print a patch adding the words of FILEs, or stdin, that
+                                   DICTIONARY leaves untranslated at least N times (1 by default)
+    meddl profiles                 list the built-in dialect profiles
+    meddl lint FILE                check a dictionary for mistakes
+    meddl migrate FILE             print FILE upgraded to the newest dictionary format
+    meddl dump [OVERLAY...]        print the built-in dictionary with OVERLAYs merged on top
+    meddl help                     show this message";
+
+fn main() -> ExitCode {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let (command, rest) = match args.split_first() {
+        Some((command, rest)) => (command.as_str(), rest),
+        None => ("translate", &args[..]),
+    };
+
+    let result = match command {
+        "translate" => translate(rest),
+        "lint" => lint(rest),
+        "score" => score(rest),
+        "stats" => stats(rest),
+        "suggest" => suggest(rest),
+        "profiles" => {
+            Dictionary::profiles().for_each(|profile| println!("{}", profile));
+            Ok(ExitCode::SUCCESS)
+        }
+        "migrate" => migrate(rest),
+        "dump" => dump(rest),
+        "help" | "-h" | "--help" => {
+            println!("{}", USAGE);
+            Ok(ExitCode::SUCCESS)
+        }
+        _ => translate(&args),
+    };
+
+    match result {
+        Ok(code) => code,
+        Err(message) => {
+            eprintln!("meddl: {}", message);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn translate(mut args: &[String]) -> Result<ExitCode, String> {
+    let mut translator = Translator::new();
+    let mut ssml = false;
+    loop {
+        match args {
+            [option, name, rest @ ..] if option == "--profile" => {
+                translator = translator.with_profile(name).map_err(|error| error.to_string())?;
+                args = rest;
+            }
+            [option] if option == "--profile" => return Err(format!("--profile expects a name\n\n{}", USAGE)),
+            [option, rest @ ..] if option == "--fillers" => {
+                translator = translator.with_fillers();
+                args = rest;
+            }
+            [option, rest @ ..] if option == "--numbers" => {
+                translator = translator.with_number_words();
+                args = rest;
+            }
+            [option, rest @ ..] if option == "--transliteration" => {
+                translator = translator.with_transliteration();
+                args = rest;
+            }
+            [option, rest @ ..] if option == "--ssml" => {
+                ssml = true;
+                args = rest;
+            }
+            _ => break,
+        }
+    }
+
+    if ssml {
+        let original = match args {
+            [] => io::read_to_string(io::stdin()).map_err(|error| error.to_string())?,
+            _ => args.join(" "),
+        };
+        println!("{}", translator.translate_ssml(&original));
+    } else if args.is_empty() {
+        let stdin = io::stdin();
+        let stdout = io::stdout();
+        stream::translate_stream_with(&translator, stdin.lock(), stdout.lock()).map_err(|error| error.to_string())?;
+    } else {
+        println!("{}", translator.translate(&args.join(" ")));
+    }
+
+    Ok(ExitCode::SUCCESS)
+}
+
+fn score(args: &[String]) -> Result<ExitCode, String> {
+    let text = match args {
+        [] => io::read_to_string(io::stdin()).map_err(|error| error.to_string())?,
+        _ => args.join(" "),
+    };
+    let analysis = Translator::new().analyze(&text);
+
+    let mut stdout = io::stdout().lock();
+    writeln!(stdout, "{:.2}", analysis.score).map_err(|error| error.to_string())?;
+    for evidence in &analysis.evidence {
+        let kind = match evidence.kind {
+            EvidenceKind::Replacement => "replacement",
+            EvidenceKind::TwistedChars => "twisted",
+            EvidenceKind::Suffix => "suffix",
+        };
+        writeln!(
+            stdout,
+            "{}..{}\t{}\t{}\t{}",
+            evidence.span.start, evidence.span.end, kind, evidence.text, evidence.matched
+        )
+        .map_err(|error| error.to_string())?;
+    }
+
+    Ok(ExitCode::SUCCESS)
+}
+
+fn stats(mut args: &[String]) -> Result<ExitCode, String> {
+    let mut translator = Translator::new();
+    let mut json = false;
+    let mut limit = 20;
+    loop {
+        match args {
+            [option, name, rest @ ..] if option == "--profile" => {
+                translator = translator.with_profile(name).map_err(|error| error.to_string())?;
+                args = rest;
+            }
+            [option, rest @ ..] if option == "--json" => {
+                json = true;
+                args = rest;
+            }
+            [option, value, rest @ ..] if option == "--limit" => {
+                limit = value
+                    .parse()
+                    .map_err(|_| format!("--limit expects a number\n\n{}", USAGE))?;
+                args = rest;
+            }
+            [option] if option == "--profile" || option == "--limit" => {
+                return Err(format!("{} expects a value\n\n{}", option, USAGE))
+            }
+            _ => break,
+        }
+    }
+
+    let stats = translator.stats(&read_corpus(args)?);
+
+    match json {
+        true => println!("{}", stats.to_json()),
+        false => print!("{}", stats.table(limit)),
+    }
+
+    Ok(ExitCode::SUCCESS)
+}
+
+fn suggest(mut args: &[String]) -> Result<ExitCode, String> {
+    let mut min_count = 1;
+    if let [option, value, rest @ ..] = args {
+        if option == "--min-count" {
+            min_count = value
+                .parse()
+                .map_err(|_| format!("--min-count expects a number\n\n{}", USAGE))?;
+            args = rest;
+        }
+    }
+    let (path, corpus) = match args {
+        [path, corpus @ ..] if !path.starts_with("--") => (path, corpus),
+        _ => return Err(format!("suggest expects a dictionary\n\n{}", USAGE)),
+    };
+
+    let json = fs::read_to_string(path).map_err(|error| format!("{}: {}", path, error))?;
+    let dictionary = Dictionary::from_json(&json).map_err(|error| format!("{}: {}", path, error))?;
+    let suggestions = meddl_translate::suggest::suggest(&read_corpus(corpus)?, &dictionary, min_count);
+    // the patch applies from the current directory, if the dictionary is somewhere below it.
+    let relative = env::current_dir()
+        .ok()
+        .and_then(|directory| Path::new(path).strip_prefix(directory).ok().and_then(Path::to_str).map(String::from))
+        .unwrap_or_else(|| path.clone());
+    if Path::new(&relative).is_absolute() {
+        return Err(format!("{}: run suggest from a directory containing the dictionary", path));
+    }
+    let patch = meddl_translate::suggest::patch(&json, &relative, &suggestions).map_err(|error| format!("{}: {}", path, error))?;
+    print!("{}", patch);
+    eprintln!("{} suggestion(s)", suggestions.len());
+
+    Ok(ExitCode::SUCCESS)
+}
+
+/// The contents of the files at `paths`, or stdin if there are none.
+fn read_corpus(paths: &[String]) -> Result<String, String> {
+    if paths.is_empty() {
+        return io::read_to_string(io::stdin()).map_err(|error| error.to_string());
+    }
+
+    let mut corpus = String::new();
+    for path in paths {
+        corpus.push_str(&fs::read_to_string(path).map_err(|error| format!("{}: {}", path, error))?);
+        corpus.push('\n');
+    }
+
+    Ok(corpus)
+}
+
+fn lint(args: &[String]) -> Result<ExitCode, String> {
+    let path = match args {
+        [path] => path,
+        _ => return Err(format!("lint expects exactly one file\n\n{}", USAGE)),
+    };
+    let json = fs::read_to_string(path).map_err(|error| format!("{}: {}", path, error))?;
+    let diagnostics = Dictionary::lint(&json);
+
+    let mut stdout = io::stdout().lock();
+    for diagnostic in &diagnostics {
+        writeln!(stdout, "{}", diagnostic).map_err(|error| error.to_string())?;
+    }
+
+    let errors = diagnostics
+        .iter()
+        .filter(|diagnostic| diagnostic.severity == Severity::Error)
+        .count();
+    eprintln!("{} error(s), {} warning(s)", errors, diagnostics.len() - errors);
+
+    if errors > 0 {
+        return Ok(ExitCode::FAILURE);
+    }
+
+    Ok(ExitCode::SUCCESS)
+}
+
+fn migrate(args: &[String]) -> Result<ExitCode, String> {
+    let path = match args {
+        [path] => path,
+        _ => return Err(format!("migrate expects exactly one file\n\n{}", USAGE)),
+    };
+    let json = fs::read_to_string(path).map_err(|error| format!("{}: {}", path, error))?;
+    let migrated = Dictionary::migrate(&json).map_err(|error| format!("{}: {}", path, error))?;
+    println!("{}", migrated);
+
+    Ok(ExitCode::SUCCESS)
+}
+
+fn dump(args: &[String]) -> Result<ExitCode, String> {
+    let mut layered = LayeredDictionary::new().with_layer(Layer::builtin());
+
+    for path in args {
+        let json = fs::read_to_string(path).map_err(|error| format!("{}: {}", path, error))?;
+        let layer = Layer::from_json(&json).map_err(|error| format!("{}: {}", path, error))?;
+        layered = layered.with_layer(layer);
+    }
+
+    let json = serde_json::to_string_pretty(&layered.effective()).map_err(|error| error.to_string())?;
+    println!("{}", json);
+
+    Ok(ExitCode::SUCCESS)
+}
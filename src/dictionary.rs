@@ -0,0 +1,365 @@
+use std::borrow::Cow;
+use std::collections::{HashMap, HashSet};
+use std::convert::TryFrom;
+use std::fmt;
+use std::sync::OnceLock;
+
+use crate::normalize::transliterate;
+use crate::Error;
+
+#[cfg(any(feature = "toml", feature = "yaml", feature = "csv"))]
+mod formats;
+mod ending;
+mod fillers;
+mod ignored;
+mod layer;
+mod lint;
+mod raw;
+mod rules;
+mod schema;
+
+pub use layer::{Layer, LayeredDictionary, Strategy};
+pub use lint::{Diagnostic, Severity};
+pub(crate) use schema::PAIRS;
+pub(crate) use ending::Ending;
+pub(crate) use fillers::Fillers;
+pub(crate) use ignored::Ignored;
+pub(crate) use raw::{Condition, RawDictionary, RawEnding, RawFillers, RawRule};
+pub(crate) use rules::{Context, RuleSource, Rules};
+
+pub(crate) type Text = Cow<'static, str>;
+pub(crate) type Pool = Cow<'static, [Text]>;
+pub(crate) type Pairs = Cow<'static, [(Text, Text)]>;
+
+// Static tables generated from `de-oger.json` and the other profiles by the build script.
+include!(concat!(env!("OUT_DIR"), "/builtin.rs"));
+
+/// A lookup table that is either compiled into the binary or loaded at runtime.
+#[derive(Clone)]
+pub(crate) enum Table<V: 'static> {
+    Static(&'static phf::Map<&'static str, V>),
+    Owned(HashMap<String, V>),
+}
+
+impl<V> Table<V> {
+    pub(crate) fn get(&self, key: &str) -> Option<&V> {
+        match self {
+            Table::Static(map) => map.get(key),
+            Table::Owned(map) => map.get(key),
+        }
+    }
+
+    pub(crate) fn len(&self) -> usize {
+        match self {
+            Table::Static(map) => map.len(),
+            Table::Owned(map) => map.len(),
+        }
+    }
+
+    pub(crate) fn keys(&self) -> Box<dyn Iterator<Item = &str> + '_> {
+        match self {
+            Table::Static(map) => Box::new(map.keys().copied()),
+            Table::Owned(map) => Box::new(map.keys().map(String::as_str)),
+        }
+    }
+}
+
+/// The words, pools and rules used for translating.
+///
+/// The built-in dictionary is compiled into the binary, using it does not need any parsing.
+/// Other dictionaries can be loaded from JSON using the same format as `de-oger.json`.
+///
+/// # Example
+///
+/// ```
+/// use meddl_translate::{Dictionary, Translator};
+///
+/// let dictionary = Dictionary::from_json(r#"{"translations": {"Hallo": ["Meddl"]}}"#).unwrap();
+/// let translator = Translator::new().with_dictionary(dictionary);
+///
+/// assert_eq!(translator.translate("Hallo"), "Meddl");
+/// ```
+#[derive(Clone)]
+pub struct Dictionary {
+    pub(crate) translations: Table<Pool>,
+    /// Number of words of the longest key in `translations`.
+    pub(crate) longest_phrase: usize,
+    pub(crate) ignored: Ignored,
+    pub(crate) question_mark: Pool,
+    pub(crate) exclamation_mark: Pool,
+    pub(crate) dot: Pool,
+    pub(crate) twisted_chars: Pairs,
+    pub(crate) twist_beginning: Pairs,
+    pub(crate) en: Cow<'static, [Ending]>,
+    pub(crate) quotation_mark: Text,
+    #[cfg_attr(not(feature = "interlude"), allow(dead_code))]
+    pub(crate) interlude: Text,
+    pub(crate) rules: Rules,
+    pub(crate) openers: Fillers,
+    pub(crate) interjections: Fillers,
+    pub(crate) closers: Fillers,
+    /// Words for the whole numbers from 0 on.
+    pub(crate) numbers: Pool,
+    /// IPA transcriptions of lowercase words, used by the `phonetic` module.
+    #[cfg_attr(not(feature = "phonetic"), allow(dead_code))]
+    pub(crate) pronunciations: Table<Text>,
+    /// Translated and ignored words containing umlauts or ß by their transliteration, built
+    /// on first use.
+    pub(crate) transliterations: OnceLock<HashMap<String, String>>,
+    /// Lowercase one-word translations that aren't German words themselves, built on first use
+    /// by the `analysis` module.
+    pub(crate) replacements: OnceLock<HashSet<String>>,
+}
+
+impl Dictionary {
+    /// The dictionary shipped with this crate, which is the `"oger"` profile.
+    pub fn builtin() -> &'static Dictionary {
+        &BUILTIN
+    }
+
+    /// The built-in dialect profile called `name`, see [`Dictionary::profiles`].
+    pub fn profile(name: &str) -> Option<&'static Dictionary> {
+        PROFILES
+            .iter()
+            .find(|(profile, _)| *profile == name)
+            .map(|(_, dictionary)| *dictionary)
+    }
+
+    /// Names of all built-in dialect profiles.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meddl_translate::Dictionary;
+    ///
+    /// assert!(Dictionary::profiles().any(|profile| profile == "bavarian"));
+    /// ```
+    pub fn profiles() -> impl Iterator<Item = &'static str> {
+        PROFILES.iter().map(|(profile, _)| *profile)
+    }
+
+    /// Loads a dictionary from JSON, sections missing in `json` are left empty.
+    ///
+    /// Dictionaries using an older version of the format are migrated, see [`Dictionary::migrate`].
+    pub fn from_json(json: &str) -> Result<Dictionary, Error> {
+        Dictionary::from_value(serde_json::from_str(json)?)
+    }
+
+    pub(crate) fn from_value(value: serde_json::Value) -> Result<Dictionary, Error> {
+        let raw: RawDictionary = serde_json::from_value(serde_json::Value::Object(schema::migrate(value)?))?;
+
+        Dictionary::try_from(raw)
+    }
+
+    pub(crate) fn translation(&self, word: &str) -> Option<&[Text]> {
+        self.translations.get(word).map(|pool| pool.as_ref())
+    }
+
+    pub(crate) fn is_ignored(&self, word: &str) -> bool {
+        self.ignored.contains(word)
+    }
+
+    /// The translated or ignored word `word` is a transliteration of, like "großen" for
+    /// "grossen".
+    pub(crate) fn transliterated(&self, word: &str) -> Option<&str> {
+        let transliterations = self.transliterations.get_or_init(|| {
+            self.translations
+                .keys()
+                .chain(self.ignored.words())
+                .filter_map(|key| match transliterate(key) {
+                    Cow::Owned(transliterated) => Some((transliterated, String::from(key))),
+                    Cow::Borrowed(_) => None,
+                })
+                .collect()
+        });
+
+        transliterations.get(word).map(String::as_str)
+    }
+
+    /// Whether the lowercase `word` is a one-word translation, like "meddl" or "loide", but not
+    /// translated or ignored itself.
+    pub(crate) fn is_replacement(&self, word: &str) -> bool {
+        let replacements = self.replacements.get_or_init(|| {
+            let german: HashSet<String> = self
+                .translations
+                .keys()
+                .chain(self.ignored.words())
+                .flat_map(|key| key.split(' '))
+                .map(str::to_lowercase)
+                .collect();
+
+            self.translations
+                .keys()
+                .filter_map(|key| self.translations.get(key))
+                .flat_map(|pool| pool.iter())
+                .map(|replacement| replacement.trim_matches(|c: char| !c.is_alphabetic()))
+                .filter(|word| !word.is_empty() && word.chars().all(char::is_alphabetic))
+                .map(str::to_lowercase)
+                .filter(|word| !german.contains(word))
+                .collect()
+        });
+
+        replacements.contains(word)
+    }
+}
+
+impl TryFrom<RawDictionary> for Dictionary {
+    type Error = Error;
+
+    fn try_from(raw: RawDictionary) -> Result<Dictionary, Error> {
+        let longest_phrase = raw.longest_phrase();
+        let dictionary = Dictionary {
+            longest_phrase,
+            translations: Table::Owned(
+                raw.translations
+                    .into_iter()
+                    .map(|(word, pool)| (word, owned_pool(pool)))
+                    .collect(),
+            ),
+            ignored: Ignored::from_entries(raw.ignored, raw.ignore_case)?,
+            question_mark: owned_pool(raw.question_mark),
+            exclamation_mark: owned_pool(raw.exclamation_mark),
+            dot: owned_pool(raw.dot),
+            twisted_chars: owned_pairs(raw.twisted_chars),
+            twist_beginning: owned_pairs(raw.twist_beginning),
+            en: Cow::Owned(raw.en.into_iter().map(Ending::from).collect()),
+            quotation_mark: Cow::Owned(raw.quotation_mark.unwrap_or_else(|| String::from("\""))),
+            interlude: Cow::Owned(raw.interlude),
+            rules: Rules::new(Cow::Owned(raw.rules.into_iter().map(RuleSource::from).collect())),
+            openers: Fillers::try_from(raw.openers)?,
+            interjections: Fillers::try_from(raw.interjections)?,
+            closers: Fillers::try_from(raw.closers)?,
+            numbers: owned_pool(raw.numbers),
+            pronunciations: Table::Owned(
+                raw.pronunciations
+                    .into_iter()
+                    .map(|(word, ipa)| (word, Cow::Owned(ipa)))
+                    .collect(),
+            ),
+            transliterations: OnceLock::new(),
+            replacements: OnceLock::new(),
+        };
+        dictionary.rules.compile()?;
+
+        Ok(dictionary)
+    }
+}
+
+impl fmt::Debug for Dictionary {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Dictionary")
+            .field("translations", &self.translations.len())
+            .field("ignored", &self.ignored.len())
+            .field("rules", &self.rules.len())
+            .finish()
+    }
+}
+
+fn owned_pool(pool: Vec<String>) -> Pool {
+    Cow::Owned(pool.into_iter().map(Cow::Owned).collect())
+}
+
+fn owned_pairs<I: IntoIterator<Item = (String, String)>>(pairs: I) -> Pairs {
+    Cow::Owned(
+        pairs
+            .into_iter()
+            .map(|(from, to)| (Cow::Owned(from), Cow::Owned(to)))
+            .collect(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    mod builtin {
+        use crate::Dictionary;
+
+        #[test]
+        fn should_contain_the_json_dictionary() {
+            let dictionary = Dictionary::builtin();
+
+            assert!(dictionary.translation("Rainer").unwrap().len() > 1);
+            assert!(dictionary.is_ignored("den"));
+            assert_eq!(dictionary.twist_beginning[0].0, "sp");
+            assert!(dictionary.quotation_mark.starts_with("*ferstellt Stimme:*"));
+        }
+
+        #[test]
+        fn should_equal_the_parsed_json() {
+            let parsed = Dictionary::from_json(include_str!("de-oger.json")).unwrap();
+            let builtin = Dictionary::builtin();
+
+            assert_eq!(parsed.translations.len(), builtin.translations.len());
+            assert_eq!(parsed.twisted_chars, builtin.twisted_chars);
+            assert_eq!(parsed.en, builtin.en);
+            assert_eq!(parsed.dot, builtin.dot);
+            assert_eq!(parsed.closers, builtin.closers);
+            assert_eq!(parsed.translation("Lord"), builtin.translation("Lord"));
+        }
+    }
+
+    mod profile {
+        use crate::Dictionary;
+
+        #[test]
+        fn should_start_with_the_default_dictionary() {
+            assert_eq!(Dictionary::profiles().next(), Some("oger"));
+            assert!(std::ptr::eq(Dictionary::profile("oger").unwrap(), Dictionary::builtin()));
+        }
+
+        #[test]
+        fn should_find_every_listed_profile() {
+            for profile in Dictionary::profiles() {
+                assert!(Dictionary::profile(profile).unwrap().translations.len() > 0);
+            }
+            assert!(Dictionary::profile("klingon").is_none());
+        }
+
+        #[test]
+        fn should_lint_every_profile_without_errors() {
+            let files = [
+                include_str!("profiles/franconian.json"),
+                include_str!("profiles/swabian.json"),
+                include_str!("profiles/bavarian.json"),
+            ];
+
+            for json in files.iter() {
+                assert!(Dictionary::lint(json).is_empty());
+            }
+        }
+    }
+
+    mod from_json {
+        use crate::Dictionary;
+
+        #[test]
+        fn should_default_missing_sections() {
+            let dictionary = Dictionary::from_json("{}").unwrap();
+
+            assert_eq!(dictionary.translations.len(), 0);
+            assert_eq!(dictionary.quotation_mark, "\"");
+        }
+
+        #[test]
+        fn should_not_count_spaces_around_keys_as_phrases() {
+            let words = Dictionary::from_json(r#"{"translations": {"euro ": ["Barrne"], " Hallo  ": ["Meddl"]}}"#).unwrap();
+            let phrases = Dictionary::from_json(r#"{"translations": {"euro ": ["Barrne"], "so ist es ": ["basst"]}}"#).unwrap();
+
+            assert_eq!(words.longest_phrase, 1);
+            assert_eq!(phrases.longest_phrase, 3);
+            assert_eq!(Dictionary::builtin().longest_phrase, 3);
+        }
+
+        #[test]
+        fn should_reject_malformed_sections() {
+            assert!(Dictionary::from_json(r#"{"translations": {"Hallo": [1]}}"#).is_err());
+        }
+
+        #[test]
+        fn should_migrate_unversioned_dictionaries() {
+            let dictionary = Dictionary::from_json(r#"{"en": {"nen": "nene", "en": "ne"}}"#).unwrap();
+
+            assert_eq!(dictionary.en[0].suffix, "en");
+            assert_eq!(dictionary.en[1].replacement, "nene");
+        }
+    }
+}
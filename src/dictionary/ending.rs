@@ -0,0 +1,37 @@
+use std::borrow::Cow;
+
+use crate::dictionary::{RawEnding, Text};
+
+/// An `en` rule, replacing `suffix` at the end of words without a translation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Ending {
+    pub(crate) suffix: Text,
+    pub(crate) replacement: Text,
+    /// Number of characters that have to be left in front of `suffix`.
+    pub(crate) min_stem: usize,
+    /// Lowercase words the rule never applies to.
+    pub(crate) except: Cow<'static, [Text]>,
+}
+
+impl Ending {
+    /// Whether the rule applies to `word`, whose lowercase form is `lowercase`.
+    pub(crate) fn applies_to(&self, word: &str, lowercase: &str) -> bool {
+        if self.suffix.is_empty() || !word.ends_with(self.suffix.as_ref()) {
+            return false;
+        }
+        let stem = &word[..word.len() - self.suffix.len()];
+
+        stem.chars().count() >= self.min_stem && !self.except.iter().any(|except| except == lowercase)
+    }
+}
+
+impl From<RawEnding> for Ending {
+    fn from(raw: RawEnding) -> Ending {
+        Ending {
+            suffix: Cow::Owned(raw.suffix),
+            replacement: Cow::Owned(raw.replacement),
+            min_stem: raw.min_stem,
+            except: Cow::Owned(raw.except.into_iter().map(|word| Cow::Owned(word.to_lowercase())).collect()),
+        }
+    }
+}
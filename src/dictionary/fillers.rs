@@ -0,0 +1,79 @@
+use std::borrow::Cow;
+use std::convert::TryFrom;
+
+use rand::{Rng, RngCore};
+
+use crate::dictionary::{Pool, RawFillers};
+use crate::util::get_random_index;
+use crate::Error;
+
+/// Phrases inserted at sentence boundaries or between words, each time with `probability`.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct Fillers {
+    pub(crate) probability: f64,
+    pub(crate) phrases: Pool,
+}
+
+impl Fillers {
+    pub(crate) const fn new(probability: f64, phrases: Pool) -> Fillers {
+        Fillers { probability, phrases }
+    }
+
+    /// A random phrase, `None` if none should be inserted this time.
+    ///
+    /// Without phrases nothing is drawn from `rng`, so dictionaries without fillers translate
+    /// the same way they did before.
+    pub(crate) fn pick(&self, rng: &mut dyn RngCore) -> Option<&str> {
+        if self.phrases.is_empty() || self.probability <= 0.0 || !rng.gen_bool(self.probability) {
+            return None;
+        }
+
+        Some(self.phrases[get_random_index(&self.phrases, rng)].as_ref())
+    }
+}
+
+impl TryFrom<RawFillers> for Fillers {
+    type Error = Error;
+
+    fn try_from(raw: RawFillers) -> Result<Fillers, Error> {
+        if !(0.0..=1.0).contains(&raw.probability) {
+            return Err(Error::InvalidDictionary(format!(
+                "probability {} is not between 0 and 1",
+                raw.probability
+            )));
+        }
+
+        Ok(Fillers::new(
+            raw.probability,
+            Cow::Owned(raw.phrases.into_iter().map(Cow::Owned).collect()),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    mod pick {
+        use rand::rngs::StdRng;
+        use rand::SeedableRng;
+
+        use crate::Dictionary;
+
+        #[test]
+        fn should_respect_the_probability() {
+            let dictionary = Dictionary::from_json(
+                r#"{"openers": {"probability": 1, "phrases": ["Meddl!"]}, "closers": {"probability": 0, "phrases": ["gell"]}}"#,
+            )
+            .unwrap();
+            let mut rng = StdRng::seed_from_u64(1);
+
+            assert_eq!(dictionary.openers.pick(&mut rng), Some("Meddl!"));
+            assert_eq!(dictionary.closers.pick(&mut rng), None);
+            assert_eq!(dictionary.interjections.pick(&mut rng), None);
+        }
+
+        #[test]
+        fn should_reject_invalid_probabilities() {
+            assert!(Dictionary::from_json(r#"{"closers": {"probability": 1.5, "phrases": ["gell"]}}"#).is_err());
+        }
+    }
+}
@@ -0,0 +1,234 @@
+//! Dictionary formats besides JSON, each behind the cargo feature of the same name.
+
+#[cfg(feature = "csv")]
+use std::collections::HashMap;
+#[cfg(feature = "csv")]
+use std::convert::TryFrom;
+
+#[cfg(feature = "csv")]
+use crate::dictionary::RawDictionary;
+use crate::{Dictionary, Error, Layer};
+
+#[cfg(feature = "toml")]
+impl Dictionary {
+    /// Loads a dictionary from TOML, using the same sections as the JSON format.
+    ///
+    /// ```toml
+    /// dot = [".", " etzala."]
+    ///
+    /// [translations]
+    /// Hallo = ["Meddl", "Servus"]
+    /// ```
+    pub fn from_toml(toml: &str) -> Result<Dictionary, Error> {
+        Dictionary::from_value(toml::from_str(toml)?)
+    }
+}
+
+#[cfg(feature = "toml")]
+impl Layer {
+    /// Reads a layer from TOML, including its `"$merge"` table.
+    pub fn from_toml(toml: &str) -> Result<Layer, Error> {
+        Layer::from_value(toml::from_str(toml)?)
+    }
+}
+
+#[cfg(feature = "yaml")]
+impl Dictionary {
+    /// Loads a dictionary from YAML, using the same sections as the JSON format.
+    ///
+    /// ```yaml
+    /// dot: [".", " etzala."]
+    /// translations:
+    ///   Hallo: [Meddl, Servus]
+    /// ```
+    pub fn from_yaml(yaml: &str) -> Result<Dictionary, Error> {
+        Dictionary::from_value(serde_yaml::from_str(yaml)?)
+    }
+}
+
+#[cfg(feature = "yaml")]
+impl Layer {
+    /// Reads a layer from YAML, including its `"$merge"` mapping.
+    pub fn from_yaml(yaml: &str) -> Result<Layer, Error> {
+        Layer::from_value(serde_yaml::from_str(yaml)?)
+    }
+}
+
+#[cfg(feature = "csv")]
+impl Dictionary {
+    /// Loads the translations of a word list with one `source,replacement` row per alternative.
+    ///
+    /// All other sections are left empty, see [`Layer::from_csv`] for adding the words to
+    /// another dictionary instead. Lines starting with `#` are skipped.
+    ///
+    /// ```csv
+    /// Hallo,Meddl
+    /// Hallo,Servus
+    /// Rust,Rosd
+    /// ```
+    pub fn from_csv(csv: &str) -> Result<Dictionary, Error> {
+        translations_dictionary(csv, b',')
+    }
+
+    /// Like [`Dictionary::from_csv`], but with tab separated columns.
+    pub fn from_tsv(tsv: &str) -> Result<Dictionary, Error> {
+        translations_dictionary(tsv, b'\t')
+    }
+}
+
+#[cfg(feature = "csv")]
+impl Layer {
+    /// A layer adding the translations of a CSV word list, see [`Dictionary::from_csv`].
+    pub fn from_csv(csv: &str) -> Result<Layer, Error> {
+        translations_layer(csv, b',')
+    }
+
+    /// A layer adding the translations of a TSV word list, see [`Dictionary::from_tsv`].
+    pub fn from_tsv(tsv: &str) -> Result<Layer, Error> {
+        translations_layer(tsv, b'\t')
+    }
+}
+
+#[cfg(feature = "csv")]
+fn translations_dictionary(text: &str, delimiter: u8) -> Result<Dictionary, Error> {
+    let raw = RawDictionary {
+        translations: read_translations(text, delimiter)?,
+        ..Default::default()
+    };
+
+    Dictionary::try_from(raw)
+}
+
+#[cfg(feature = "csv")]
+fn translations_layer(text: &str, delimiter: u8) -> Result<Layer, Error> {
+    let translations = serde_json::to_value(read_translations(text, delimiter)?)?;
+
+    Layer::from_value(serde_json::json!({ "translations": translations }))
+}
+
+/// Collects the rows of a word list, rows with the same source become alternatives.
+#[cfg(feature = "csv")]
+fn read_translations(text: &str, delimiter: u8) -> Result<HashMap<String, Vec<String>>, Error> {
+    let mut reader = csv::ReaderBuilder::new()
+        .has_headers(false)
+        .delimiter(delimiter)
+        .comment(Some(b'#'))
+        .flexible(true)
+        .trim(csv::Trim::All)
+        .from_reader(text.as_bytes());
+    let mut translations: HashMap<String, Vec<String>> = HashMap::new();
+
+    for record in reader.records() {
+        let record = record?;
+        let (source, replacement) = match (record.get(0), record.get(1), record.len()) {
+            (Some(source), Some(replacement), 2) if !source.is_empty() => (source, replacement),
+            _ => {
+                let line = record.position().map_or(0, |position| position.line());
+                return Err(Error::InvalidDictionary(format!(
+                    "line {}: expected a source and a replacement",
+                    line
+                )));
+            }
+        };
+
+        translations
+            .entry(String::from(source))
+            .or_default()
+            .push(String::from(replacement));
+    }
+
+    Ok(translations)
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(feature = "toml")]
+    mod from_toml {
+        use crate::Dictionary;
+
+        #[test]
+        fn should_read_the_json_sections() {
+            let dictionary = Dictionary::from_toml(
+                r#"
+                dot = [" etzala."]
+
+                [translations]
+                Hallo = ["Meddl"]
+
+                [twistedChars]
+                "eug " = "euch"
+                "#,
+            )
+            .unwrap();
+
+            assert_eq!(dictionary.translation("Hallo").unwrap(), ["Meddl"]);
+            assert_eq!(dictionary.dot[0], " etzala.");
+            assert_eq!(dictionary.twisted_chars[0].0, "eug ");
+        }
+
+        #[test]
+        fn should_reject_malformed_sections() {
+            assert!(Dictionary::from_toml("dot = 1").is_err());
+        }
+    }
+
+    #[cfg(feature = "yaml")]
+    mod from_yaml {
+        use crate::{Dictionary, Layer, LayeredDictionary, Translator};
+
+        #[test]
+        fn should_read_the_json_sections() {
+            let dictionary = Dictionary::from_yaml("translations:\n  Hallo: [Meddl]\nignored: [den]\n").unwrap();
+
+            assert_eq!(dictionary.translation("Hallo").unwrap(), ["Meddl"]);
+            assert!(dictionary.is_ignored("den"));
+        }
+
+        #[test]
+        fn should_read_layers() {
+            let layer = Layer::from_yaml("$merge:\n  translations: replace\ntranslations:\n  Rust: [Rosd]\n").unwrap();
+            let dictionary = LayeredDictionary::new()
+                .with_layer(Layer::builtin())
+                .with_layer(layer)
+                .build()
+                .unwrap();
+
+            assert_eq!(Translator::new().with_dictionary(dictionary).translate("Rust"), "Rosd");
+        }
+    }
+
+    #[cfg(feature = "csv")]
+    mod from_csv {
+        use crate::{Dictionary, Layer, LayeredDictionary};
+
+        #[test]
+        fn should_collect_alternatives() {
+            let dictionary = Dictionary::from_csv("# source,replacement\nHallo,Meddl\nHallo, Servus\nRust,Rosd\n").unwrap();
+
+            assert_eq!(dictionary.translation("Hallo").unwrap(), ["Meddl", "Servus"]);
+            assert_eq!(dictionary.translation("Rust").unwrap(), ["Rosd"]);
+        }
+
+        #[test]
+        fn should_read_tab_separated_lists() {
+            let dictionary = Dictionary::from_tsv("Hallo\tMeddl, Servus\n").unwrap();
+
+            assert_eq!(dictionary.translation("Hallo").unwrap(), ["Meddl, Servus"]);
+        }
+
+        #[test]
+        fn should_reject_incomplete_rows() {
+            assert!(Dictionary::from_csv("Hallo,Meddl\nRust\n").is_err());
+        }
+
+        #[test]
+        fn should_append_to_other_layers() {
+            let effective = LayeredDictionary::new()
+                .with_layer(Layer::from_json(r#"{"translations": {"Hallo": ["Meddl"]}}"#).unwrap())
+                .with_layer(Layer::from_csv("Hallo,Servus").unwrap())
+                .effective();
+
+            assert_eq!(effective["translations"]["Hallo"], serde_json::json!(["Meddl", "Servus"]));
+        }
+    }
+}
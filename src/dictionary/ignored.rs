@@ -0,0 +1,172 @@
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::sync::OnceLock;
+
+use regex::RegexSet;
+
+use crate::dictionary::raw::{ignored_regex, is_ignored_pattern};
+use crate::dictionary::{Table, Text};
+use crate::Error;
+
+/// The `ignored` section: words looked up in a hash set and patterns matched against every word.
+///
+/// Entries wrapped in slashes (`/^[A-Z]+$/`) are regular expressions, entries containing `*`
+/// or `?` are globs matching the whole word. Everything else is a plain word.
+#[derive(Clone)]
+pub(crate) struct Ignored {
+    /// Plain words, lowercase if `ignore_case` is set.
+    words: Table<()>,
+    patterns: Cow<'static, [Text]>,
+    ignore_case: bool,
+    compiled: OnceLock<RegexSet>,
+}
+
+impl Ignored {
+    pub(crate) const fn new(words: Table<()>, patterns: Cow<'static, [Text]>, ignore_case: bool) -> Ignored {
+        Ignored {
+            words,
+            patterns,
+            ignore_case,
+            compiled: OnceLock::new(),
+        }
+    }
+
+    /// Sorts `entries` into words and patterns.
+    pub(crate) fn from_entries<I: IntoIterator<Item = String>>(entries: I, ignore_case: bool) -> Result<Ignored, Error> {
+        let mut ignored = Ignored::new(Table::Owned(HashMap::new()), Cow::Owned(Vec::new()), ignore_case);
+        ignored.extend(entries)?;
+
+        Ok(ignored)
+    }
+
+    /// Adds more words and patterns, e.g. for protecting product names in a single translator.
+    pub(crate) fn extend<I: IntoIterator<Item = String>>(&mut self, entries: I) -> Result<(), Error> {
+        let mut words = match &self.words {
+            Table::Static(map) => map.keys().map(|word| (String::from(*word), ())).collect(),
+            Table::Owned(map) => map.clone(),
+        };
+        let mut patterns = self.patterns.to_vec();
+
+        for entry in entries {
+            if pattern(&entry).is_some() {
+                patterns.push(Cow::Owned(entry));
+            } else if self.ignore_case {
+                words.insert(entry.to_lowercase(), ());
+            } else {
+                words.insert(entry, ());
+            }
+        }
+
+        self.words = Table::Owned(words);
+        self.patterns = Cow::Owned(patterns);
+        self.compiled = OnceLock::new();
+        self.compile()
+    }
+
+    /// Compiles all patterns now, instead of panicking when translating.
+    pub(crate) fn compile(&self) -> Result<(), Error> {
+        if self.compiled.get().is_some() {
+            return Ok(());
+        }
+
+        let prefix = if self.ignore_case { "(?i)" } else { "" };
+        let patterns = self
+            .patterns
+            .iter()
+            .filter_map(|entry| pattern(entry))
+            .map(|pattern| format!("{}{}", prefix, pattern));
+        let set = RegexSet::new(patterns).map_err(|error| Error::InvalidDictionary(error.to_string()))?;
+        let _ = self.compiled.set(set);
+
+        Ok(())
+    }
+
+    pub(crate) fn contains(&self, word: &str) -> bool {
+        let found = if self.ignore_case {
+            self.words.get(&word.to_lowercase()).is_some()
+        } else {
+            self.words.get(word).is_some()
+        };
+        if found || self.patterns.is_empty() {
+            return found;
+        }
+
+        self.compile().expect("Could not compile ignored patterns.");
+        self.compiled.get().is_some_and(|set| set.is_match(word))
+    }
+
+    /// The plain words, lowercase if `ignore_case` is set.
+    pub(crate) fn words(&self) -> impl Iterator<Item = &str> {
+        self.words.keys()
+    }
+
+    pub(crate) fn len(&self) -> usize {
+        self.words.len() + self.patterns.len()
+    }
+}
+
+/// The regular expression of an `ignored` entry, `None` if it's a plain word.
+pub(crate) fn pattern(entry: &str) -> Option<String> {
+    if let Some(regex) = ignored_regex(entry) {
+        return Some(String::from(regex));
+    }
+    if !is_ignored_pattern(entry) {
+        return None;
+    }
+
+    let mut regex = String::from("^");
+    for c in entry.chars() {
+        match c {
+            '*' => regex.push_str(".*"),
+            '?' => regex.push('.'),
+            c => regex.push_str(&regex::escape(c.encode_utf8(&mut [0; 4]))),
+        }
+    }
+    regex.push('$');
+
+    Some(regex)
+}
+
+#[cfg(test)]
+mod tests {
+    mod contains {
+        use crate::dictionary::ignored::Ignored;
+
+        fn ignored(entries: &[&str], ignore_case: bool) -> Ignored {
+            Ignored::from_entries(entries.iter().map(|entry| String::from(*entry)), ignore_case).unwrap()
+        }
+
+        #[test]
+        fn should_match_words_exactly() {
+            let ignored = ignored(&["den"], false);
+
+            assert!(ignored.contains("den"));
+            assert!(!ignored.contains("Den"));
+            assert!(!ignored.contains("denn"));
+        }
+
+        #[test]
+        fn should_ignore_case_if_asked_to() {
+            let ignored = ignored(&["Rust", "Meddl*"], true);
+
+            assert!(ignored.contains("rust"));
+            assert!(ignored.contains("MEDDLLOCH"));
+        }
+
+        #[test]
+        fn should_match_globs_and_regular_expressions() {
+            let ignored = ignored(&["Meddl*", "R?st", "/^[A-Z]{2,}$/"], false);
+
+            assert!(ignored.contains("Meddlloch"));
+            assert!(ignored.contains("Rost"));
+            assert!(!ignored.contains("Roost"));
+            assert!(ignored.contains("HTML"));
+            assert!(!ignored.contains("Html"));
+        }
+
+        #[test]
+        fn should_reject_invalid_expressions() {
+            assert!(Ignored::from_entries(vec![String::from("/(/")], false).is_err());
+        }
+    }
+}
@@ -0,0 +1,348 @@
+use std::collections::HashMap;
+use std::convert::TryFrom;
+
+use serde_json::{Map, Value};
+
+use crate::dictionary::{schema, RawDictionary, PAIRS};
+use crate::{Dictionary, Error};
+
+/// Key of an overlay holding the merge strategy of each of its sections.
+const MERGE_KEY: &str = "$merge";
+
+/// How a [`Layer`] changes a section of the layers below it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Strategy {
+    /// Adds alternatives to existing translations and pools and new entries to everything else.
+    Append,
+    /// Replaces existing translations and rules, pools and the ignore list as a whole.
+    Replace,
+    /// Removes the listed translations, rules, alternatives and ignored words.
+    Remove,
+}
+
+impl Strategy {
+    fn parse(name: &str) -> Option<Strategy> {
+        match name {
+            "append" => Some(Strategy::Append),
+            "replace" => Some(Strategy::Replace),
+            "remove" => Some(Strategy::Remove),
+            _ => None,
+        }
+    }
+}
+
+/// A dictionary source stacked on top of others by [`LayeredDictionary`].
+///
+/// The strategy of each section can be given in code or by the layer itself, using a
+/// `"$merge"` object in its JSON:
+///
+/// ```json
+/// {
+///     "$merge": { "translations": "append", "ignored": "remove" },
+///     "translations": { "Hallo": ["Servus"] },
+///     "ignored": ["den"]
+/// }
+/// ```
+///
+/// Sections without a strategy are appended.
+#[derive(Debug, Clone)]
+pub struct Layer {
+    sections: Map<String, Value>,
+    strategies: HashMap<String, Strategy>,
+}
+
+impl Layer {
+    /// A layer holding the built-in dictionary.
+    pub fn builtin() -> Layer {
+        Layer::from_json(include_str!("../de-oger.json")).expect("Could not parse translation.")
+    }
+
+    /// Reads a layer from JSON, using the same format as `de-oger.json` in any version.
+    pub fn from_json(json: &str) -> Result<Layer, Error> {
+        Layer::from_value(serde_json::from_str(json)?)
+    }
+
+    pub(super) fn from_value(value: Value) -> Result<Layer, Error> {
+        // layers are merged in the newest format, whatever version they were written in.
+        let mut sections = schema::migrate(value)?;
+        let mut strategies = HashMap::new();
+
+        if let Some(merge) = sections.remove(MERGE_KEY) {
+            let merge = merge
+                .as_object()
+                .ok_or_else(|| Error::InvalidDictionary(format!("{} must be an object", MERGE_KEY)))?;
+
+            for (section, strategy) in merge {
+                let strategy = strategy
+                    .as_str()
+                    .and_then(Strategy::parse)
+                    .ok_or_else(|| Error::InvalidDictionary(format!("unknown merge strategy for {}", section)))?;
+                strategies.insert(section.clone(), strategy);
+            }
+        }
+
+        Ok(Layer { sections, strategies })
+    }
+
+    /// Sets how `section` (e.g. `"translations"`) is merged into the layers below.
+    pub fn with_strategy(mut self, section: &str, strategy: Strategy) -> Layer {
+        self.strategies.insert(String::from(section), strategy);
+        self
+    }
+
+    fn strategy(&self, section: &str) -> Strategy {
+        self.strategies.get(section).copied().unwrap_or(Strategy::Append)
+    }
+}
+
+/// A dictionary made of several layers, each one changing the result of the layers below.
+///
+/// # Example
+///
+/// ```
+/// use meddl_translate::{Layer, LayeredDictionary, Strategy, Translator};
+///
+/// let overlay = Layer::from_json(r#"{"translations": {"Rust": ["Rosd"]}, "ignored": ["den"]}"#)
+///     .unwrap()
+///     .with_strategy("ignored", Strategy::Remove);
+/// let layered = LayeredDictionary::new()
+///     .with_layer(Layer::builtin())
+///     .with_layer(overlay);
+///
+/// let effective = layered.effective();
+/// assert!(!effective["ignored"].as_array().unwrap().contains(&"den".into()));
+///
+/// let translator = Translator::new().with_dictionary(layered.build().unwrap());
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct LayeredDictionary {
+    layers: Vec<Layer>,
+}
+
+impl LayeredDictionary {
+    pub fn new() -> LayeredDictionary {
+        LayeredDictionary::default()
+    }
+
+    /// Puts `layer` on top of all layers added so far.
+    pub fn with_layer(mut self, layer: Layer) -> LayeredDictionary {
+        self.layers.push(layer);
+        self
+    }
+
+    /// The merged dictionary as JSON, e.g. for inspecting what the layers add up to.
+    pub fn effective(&self) -> Value {
+        let mut effective = Map::new();
+
+        for layer in &self.layers {
+            for (section, value) in &layer.sections {
+                let strategy = layer.strategy(section);
+                let merged = if PAIRS.contains(&section.as_str()) {
+                    merge_pairs(effective.remove(section), value, strategy)
+                } else {
+                    merge(effective.remove(section), value, strategy)
+                };
+
+                if let Some(merged) = merged {
+                    effective.insert(section.clone(), merged);
+                }
+            }
+        }
+
+        Value::Object(effective)
+    }
+
+    /// Merges all layers into a [`Dictionary`].
+    pub fn build(&self) -> Result<Dictionary, Error> {
+        let raw: RawDictionary = serde_json::from_value(self.effective())?;
+
+        Dictionary::try_from(raw)
+    }
+}
+
+/// Merges the `layer` value of a section into the `below` value, `None` removes the section.
+fn merge(below: Option<Value>, layer: &Value, strategy: Strategy) -> Option<Value> {
+    let below = match below {
+        Some(below) => below,
+        None if strategy == Strategy::Remove => return None,
+        None => return Some(layer.clone()),
+    };
+
+    match (below, layer) {
+        (Value::Object(mut below), Value::Object(layer)) => {
+            for (key, value) in layer {
+                match strategy {
+                    Strategy::Remove => remove_entry(&mut below, key, value),
+                    Strategy::Replace => {
+                        below.insert(key.clone(), value.clone());
+                    }
+                    Strategy::Append => {
+                        let merged = match below.remove(key) {
+                            Some(Value::Array(existing)) => append(existing, value),
+                            _ => value.clone(),
+                        };
+                        below.insert(key.clone(), merged);
+                    }
+                }
+            }
+            Some(Value::Object(below))
+        }
+        (Value::Array(below), Value::Array(layer)) => match strategy {
+            Strategy::Remove => Some(Value::Array(below.into_iter().filter(|entry| !layer.contains(entry)).collect())),
+            Strategy::Replace => Some(Value::Array(layer.clone())),
+            Strategy::Append => Some(append(below, &Value::Array(layer.clone()))),
+        },
+        // whole sections like "interlude" can only be replaced or removed.
+        _ if strategy == Strategy::Remove => None,
+        (_, layer) => Some(layer.clone()),
+    }
+}
+
+/// Merges lists of `[from, to]` pairs like objects, keeping the position of replaced pairs.
+fn merge_pairs(below: Option<Value>, layer: &Value, strategy: Strategy) -> Option<Value> {
+    let (mut below, layer) = match (below, layer) {
+        (Some(Value::Array(below)), Value::Array(layer)) => (below, layer),
+        (below, layer) => return merge(below, layer, strategy),
+    };
+
+    for pair in layer {
+        let position = below.iter().position(|existing| pair_key(existing) == pair_key(pair));
+
+        match (strategy, position) {
+            (Strategy::Remove, Some(position)) => {
+                below.remove(position);
+            }
+            (Strategy::Remove, None) => {}
+            (_, Some(position)) => below[position] = pair.clone(),
+            (_, None) => below.push(pair.clone()),
+        }
+    }
+
+    Some(Value::Array(below))
+}
+
+/// The first element of a pair, or the suffix of an `en` entry written as an object.
+fn pair_key(pair: &Value) -> Option<&Value> {
+    pair.get(0).or_else(|| pair.get("suffix"))
+}
+
+/// Removes `key` from `section`, or only the given alternatives if `value` is a non-empty array.
+fn remove_entry(section: &mut Map<String, Value>, key: &str, value: &Value) {
+    let alternatives = match value.as_array() {
+        Some(alternatives) if !alternatives.is_empty() => alternatives,
+        _ => {
+            section.remove(key);
+            return;
+        }
+    };
+
+    if let Some(Value::Array(existing)) = section.get_mut(key) {
+        existing.retain(|entry| !alternatives.contains(entry));
+        if existing.is_empty() {
+            section.remove(key);
+        }
+    }
+}
+
+fn append(mut existing: Vec<Value>, value: &Value) -> Value {
+    let additions = match value {
+        Value::Array(additions) => additions.clone(),
+        other => vec![other.clone()],
+    };
+
+    for addition in additions {
+        if !existing.contains(&addition) {
+            existing.push(addition);
+        }
+    }
+
+    Value::Array(existing)
+}
+
+#[cfg(test)]
+mod tests {
+    mod effective {
+        use serde_json::json;
+
+        use crate::{Layer, LayeredDictionary, Strategy};
+
+        fn merged(base: &str, overlay: Layer) -> serde_json::Value {
+            LayeredDictionary::new()
+                .with_layer(Layer::from_json(base).unwrap())
+                .with_layer(overlay)
+                .effective()
+        }
+
+        #[test]
+        fn should_append_alternatives() {
+            let overlay = Layer::from_json(r#"{"translations": {"Hallo": ["Meddl", "Servus"], "Rust": ["Rosd"]}}"#).unwrap();
+
+            assert_eq!(
+                merged(r#"{"translations": {"Hallo": ["Meddl"]}}"#, overlay),
+                json!({"version": 2, "translations": {"Hallo": ["Meddl", "Servus"], "Rust": ["Rosd"]}})
+            );
+        }
+
+        #[test]
+        fn should_replace_entries() {
+            let overlay = Layer::from_json(r#"{"translations": {"Hallo": ["Servus"]}, "dot": ["."]}"#)
+                .unwrap()
+                .with_strategy("translations", Strategy::Replace)
+                .with_strategy("dot", Strategy::Replace);
+
+            assert_eq!(
+                merged(r#"{"translations": {"Hallo": ["Meddl"], "Rust": ["Rosd"]}, "dot": [" etzala."]}"#, overlay),
+                json!({"version": 2, "translations": {"Hallo": ["Servus"], "Rust": ["Rosd"]}, "dot": ["."]})
+            );
+        }
+
+        #[test]
+        fn should_remove_keys_alternatives_and_words() {
+            let overlay = Layer::from_json(
+                r#"{"$merge": {"translations": "remove", "ignored": "remove", "interlude": "remove"},
+                    "translations": {"Hallo": [], "Rust": ["Rosd"]}, "ignored": ["den"], "interlude": null}"#,
+            )
+            .unwrap();
+
+            assert_eq!(
+                merged(
+                    r#"{"translations": {"Hallo": ["Meddl"], "Rust": ["Rosd", "Rusd"]}, "ignored": ["den", "wenn"], "interlude": "..."}"#,
+                    overlay
+                ),
+                json!({"version": 2, "translations": {"Rust": ["Rusd"]}, "ignored": ["wenn"]})
+            );
+        }
+
+        #[test]
+        fn should_merge_ordered_sections_by_key() {
+            let overlay = Layer::from_json(r#"{"twistedChars": {"t": "dd", "v": "f"}, "en": [["nen", "nene"]]}"#)
+                .unwrap()
+                .with_strategy("en", Strategy::Remove);
+
+            assert_eq!(
+                merged(r#"{"version": 2, "twistedChars": [["tz", "ds"], ["t", "d"]], "en": [["nen", "ne"], ["en", "ne"]]}"#, overlay),
+                json!({"version": 2, "twistedChars": [["tz", "ds"], ["t", "dd"], ["v", "f"]], "en": [["en", "ne"]]})
+            );
+        }
+
+        #[test]
+        fn should_reject_unknown_strategies() {
+            assert!(Layer::from_json(r#"{"$merge": {"translations": "shuffle"}}"#).is_err());
+        }
+    }
+
+    mod build {
+        use crate::{Layer, LayeredDictionary, Translator};
+
+        #[test]
+        fn should_build_a_usable_dictionary() {
+            let dictionary = LayeredDictionary::new()
+                .with_layer(Layer::builtin())
+                .with_layer(Layer::from_json(r#"{"$merge": {"translations": "replace"}, "translations": {"Rust": ["Rosd"]}}"#).unwrap())
+                .build()
+                .unwrap();
+
+            assert_eq!(Translator::new().with_dictionary(dictionary).with_seed(1).translate("Rust"), "Rosd");
+        }
+    }
+}
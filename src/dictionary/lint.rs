@@ -0,0 +1,570 @@
+use std::collections::HashSet;
+use std::fmt;
+
+use regex::Regex;
+use serde_json::{Map, Value};
+
+use crate::dictionary::{ignored, schema, PAIRS};
+use crate::punctuation_regex;
+use crate::Dictionary;
+
+const POOLS: [&str; 3] = ["questionMark", "exclamationMark", "dot"];
+const TEXTS: [&str; 2] = ["quotationMark", "interlude"];
+const CONDITIONS: [&str; 2] = ["noun", "sentenceStart"];
+const FILLERS: [&str; 3] = ["openers", "interjections", "closers"];
+
+/// How bad a [`Diagnostic`] is.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Severity {
+    /// The entry is useless or surprising, but the dictionary can be used.
+    Warning,
+    /// The dictionary can't be loaded or crashes older versions of this crate.
+    Error,
+}
+
+/// A problem found by [`Dictionary::lint`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Diagnostic {
+    pub severity: Severity,
+    /// JSON path of the offending entry, e.g. `$.translations.Rainer[3]`.
+    pub path: String,
+    pub message: String,
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let severity = match self.severity {
+            Severity::Warning => "warning",
+            Severity::Error => "error",
+        };
+
+        write!(f, "{}: {}: {}", severity, self.path, self.message)
+    }
+}
+
+impl Dictionary {
+    /// Checks a JSON dictionary for mistakes that would otherwise only show up while translating.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meddl_translate::{Dictionary, Severity};
+    ///
+    /// let diagnostics = Dictionary::lint(r#"{"translations": {"Hallo": []}}"#);
+    ///
+    /// assert_eq!(diagnostics[0].severity, Severity::Error);
+    /// assert_eq!(diagnostics[0].path, "$.translations.Hallo");
+    /// ```
+    pub fn lint(json: &str) -> Vec<Diagnostic> {
+        let mut linter = Linter::default();
+
+        match serde_json::from_str::<Value>(json) {
+            Ok(Value::Object(root)) => linter.root(&root),
+            Ok(_) => linter.error("$", "dictionary must be an object"),
+            Err(error) => linter.error("$", &format!("invalid JSON: {}", error)),
+        }
+
+        linter.diagnostics
+    }
+}
+
+#[derive(Default)]
+struct Linter {
+    diagnostics: Vec<Diagnostic>,
+}
+
+impl Linter {
+    fn push(&mut self, severity: Severity, path: &str, message: &str) {
+        self.diagnostics.push(Diagnostic {
+            severity,
+            path: String::from(path),
+            message: String::from(message),
+        });
+    }
+
+    fn error(&mut self, path: &str, message: &str) {
+        self.push(Severity::Error, path, message);
+    }
+
+    fn warning(&mut self, path: &str, message: &str) {
+        self.push(Severity::Warning, path, message);
+    }
+
+    fn root(&mut self, root: &Map<String, Value>) {
+        let version = schema::version(root);
+        if version.is_none() {
+            self.error(
+                "$.version",
+                &format!("unsupported version, expected 1 to {}", Dictionary::VERSION),
+            );
+        }
+
+        for (key, value) in root {
+            let path = key_path("$", key);
+
+            match key.as_str() {
+                "version" => {}
+                "ignoreCase" => {
+                    if !value.is_boolean() {
+                        self.error(&path, "must be true or false");
+                    }
+                }
+                "translations" => self.translations(&path, value),
+                "ignored" => self.ignored(&path, value, root.get("translations")),
+                "rules" => self.rules(&path, value),
+                key if POOLS.contains(&key) => self.pool(&path, value),
+                "numbers" => self.pool(&path, value),
+                "pronunciations" => self.pronunciations(&path, value),
+                key if FILLERS.contains(&key) => self.fillers(&path, value),
+                key if PAIRS.contains(&key) => match version {
+                    Some(1) => self.pair_object(&path, key, value),
+                    Some(_) => self.pair_list(&path, key, value),
+                    None => {}
+                },
+                key if TEXTS.contains(&key) => {
+                    if !value.is_string() {
+                        self.error(&path, "must be a string");
+                    }
+                }
+                _ => self.warning(&path, "unknown section, it is ignored"),
+            }
+        }
+    }
+
+    fn pool(&mut self, path: &str, value: &Value) {
+        let pool = match value.as_array() {
+            Some(pool) => pool,
+            None => return self.error(path, "must be an array of strings"),
+        };
+
+        if pool.is_empty() {
+            self.error(path, "pool is empty, there is nothing to choose from");
+        }
+        for (index, entry) in pool.iter().enumerate() {
+            if !entry.is_string() {
+                self.error(&format!("{}[{}]", path, index), "must be a string");
+            }
+        }
+    }
+
+    fn fillers(&mut self, path: &str, value: &Value) {
+        let fillers = match value.as_object() {
+            Some(fillers) => fillers,
+            None => return self.error(path, "must be an object with a probability and phrases"),
+        };
+
+        for (key, value) in fillers {
+            let field_path = key_path(path, key);
+
+            match key.as_str() {
+                "probability" => {
+                    if !value.as_f64().is_some_and(|probability| (0.0..=1.0).contains(&probability)) {
+                        self.error(&field_path, "must be a number from 0 to 1");
+                    }
+                }
+                "phrases" => self.pool(&field_path, value),
+                _ => self.error(&field_path, "unknown field"),
+            }
+        }
+        if fillers.get("probability").is_none_or(|probability| probability.as_f64() == Some(0.0)) {
+            self.warning(path, "probability is 0, no phrase is ever inserted");
+        }
+    }
+
+    fn pronunciations(&mut self, path: &str, value: &Value) {
+        let pronunciations = match value.as_object() {
+            Some(pronunciations) => pronunciations,
+            None => return self.error(path, "must be an object of strings"),
+        };
+
+        for (word, ipa) in pronunciations {
+            let word_path = key_path(path, word);
+
+            if !ipa.is_string() {
+                self.error(&word_path, "must be a string");
+            }
+            if word.to_lowercase() != *word {
+                self.warning(&word_path, "never matches, words are looked up in lowercase");
+            }
+        }
+    }
+
+    fn translations(&mut self, path: &str, value: &Value) {
+        let translations = match value.as_object() {
+            Some(translations) => translations,
+            None => return self.error(path, "must be an object of arrays"),
+        };
+
+        for (word, pool) in translations {
+            let word_path = key_path(path, word);
+
+            if word.starts_with(' ') || word.ends_with(' ') || word.contains("  ") {
+                self.warning(&word_path, "never matches, phrases are matched word by word");
+            } else if punctuation_regex().is_match(word) {
+                self.warning(&word_path, "never matches, punctuation is removed before looking words and phrases up");
+            }
+            self.pool(&word_path, pool);
+        }
+    }
+
+    fn ignored(&mut self, path: &str, value: &Value, translations: Option<&Value>) {
+        let ignored = match value.as_array() {
+            Some(ignored) => ignored,
+            None => return self.error(path, "must be an array of strings"),
+        };
+        let mut seen = HashSet::new();
+
+        for (index, word) in ignored.iter().enumerate() {
+            let word_path = format!("{}[{}]", path, index);
+            let word = match word.as_str() {
+                Some(word) => word,
+                None => {
+                    self.error(&word_path, "must be a string");
+                    continue;
+                }
+            };
+
+            if !seen.insert(word) {
+                self.warning(&word_path, &format!("\"{}\" is ignored more than once", word));
+            }
+            if let Some(pattern) = ignored::pattern(word) {
+                if let Err(error) = Regex::new(&pattern) {
+                    self.error(&word_path, &format!("invalid pattern: {}", error));
+                }
+                continue;
+            }
+            if translations.and_then(|translations| translations.get(word)).is_some() {
+                self.warning(&word_path, &format!("\"{}\" is also a translation, which is never used", word));
+            }
+        }
+    }
+
+    fn rules(&mut self, path: &str, value: &Value) {
+        let rules = match value.as_array() {
+            Some(rules) => rules,
+            None => return self.error(path, "must be an array of rules"),
+        };
+
+        for (index, rule) in rules.iter().enumerate() {
+            let rule_path = format!("{}[{}]", path, index);
+            let rule = match rule.as_object() {
+                Some(rule) => rule,
+                None => {
+                    self.error(&rule_path, "must be an object with a pattern and a replacement");
+                    continue;
+                }
+            };
+
+            match rule.get("pattern").map(Value::as_str) {
+                Some(Some(pattern)) => {
+                    if let Err(error) = Regex::new(pattern) {
+                        self.error(&key_path(&rule_path, "pattern"), &format!("invalid pattern: {}", error));
+                    }
+                }
+                Some(None) => self.error(&key_path(&rule_path, "pattern"), "must be a string"),
+                None => self.error(&rule_path, "pattern is missing"),
+            }
+            match rule.get("replacement") {
+                Some(replacement) if !replacement.is_string() => {
+                    self.error(&key_path(&rule_path, "replacement"), "must be a string")
+                }
+                Some(_) => {}
+                None => self.error(&rule_path, "replacement is missing"),
+            }
+            for (key, value) in rule {
+                match key.as_str() {
+                    "pattern" | "replacement" => {}
+                    "conditions" => self.conditions(&key_path(&rule_path, key), value),
+                    _ => self.error(&key_path(&rule_path, key), "unknown field"),
+                }
+            }
+        }
+    }
+
+    fn conditions(&mut self, path: &str, value: &Value) {
+        let conditions = match value.as_array() {
+            Some(conditions) => conditions,
+            None => return self.error(path, "must be an array of conditions"),
+        };
+
+        for (index, condition) in conditions.iter().enumerate() {
+            if !condition.as_str().is_some_and(|condition| CONDITIONS.contains(&condition)) {
+                self.error(
+                    &format!("{}[{}]", path, index),
+                    &format!("unknown condition, expected one of {}", CONDITIONS.join(", ")),
+                );
+            }
+        }
+    }
+
+    /// Version 1 of the format, applied in sorted key order, which is the order of `serde_json::Map`.
+    fn pair_object(&mut self, path: &str, section: &str, value: &Value) {
+        let pairs = match value.as_object() {
+            Some(pairs) => pairs,
+            None => return self.error(path, "must be an object of strings"),
+        };
+        let pairs: Vec<(String, &str, &Value)> = pairs
+            .iter()
+            .map(|(key, replacement)| (key_path(path, key), key.as_str(), replacement))
+            .collect();
+
+        self.pairs(section, &pairs);
+    }
+
+    /// Version 2 of the format, applied in the listed order.
+    fn pair_list(&mut self, path: &str, section: &str, value: &Value) {
+        let list = match value.as_array() {
+            Some(list) => list,
+            None => return self.error(path, "must be an array of [from, to] pairs"),
+        };
+        let mut pairs = Vec::new();
+
+        for (index, pair) in list.iter().enumerate() {
+            let pair_path = format!("{}[{}]", path, index);
+
+            match (pair, pair.as_array().map(Vec::as_slice)) {
+                (_, Some([Value::String(key), replacement])) => pairs.push((pair_path, key.as_str(), replacement)),
+                (Value::Object(ending), _) if section == "en" => {
+                    if let Some((key, replacement)) = self.ending_options(&pair_path, ending) {
+                        pairs.push((key_path(&pair_path, "suffix"), key, replacement));
+                    }
+                }
+                _ => self.error(&pair_path, "must be a [from, to] pair of strings"),
+            }
+        }
+
+        self.pairs(section, &pairs);
+    }
+
+    /// Checks an `en` entry written as an object, returning its suffix and replacement.
+    fn ending_options<'a>(&mut self, path: &str, ending: &'a Map<String, Value>) -> Option<(&'a str, &'a Value)> {
+        for (key, value) in ending {
+            let option_path = key_path(path, key);
+
+            match key.as_str() {
+                "suffix" | "replacement" => {}
+                "minStem" if !value.is_u64() => self.error(&option_path, "must be a non-negative integer"),
+                "except" if !value.as_array().is_some_and(|except| except.iter().all(Value::is_string)) => {
+                    self.error(&option_path, "must be an array of strings")
+                }
+                "minStem" | "except" => {}
+                _ => self.error(&option_path, "unknown field"),
+            }
+        }
+
+        match (ending.get("suffix"), ending.get("replacement")) {
+            (Some(Value::String(suffix)), Some(replacement)) => Some((suffix.as_str(), replacement)),
+            (Some(_), Some(_)) => {
+                self.error(&key_path(path, "suffix"), "must be a string");
+                None
+            }
+            _ => {
+                self.error(path, "suffix and replacement are required");
+                None
+            }
+        }
+    }
+
+    /// Checks `(path, key, replacement)` entries of an ordered section, in the order they are applied.
+    fn pairs(&mut self, section: &str, pairs: &[(String, &str, &Value)]) {
+        let keys: Vec<&str> = pairs.iter().map(|(_, key, _)| *key).collect();
+
+        for (index, (path, key, replacement)) in pairs.iter().enumerate() {
+            if !replacement.is_string() {
+                self.error(path, "must be a string");
+            }
+            if key.is_empty() {
+                self.error(path, "empty key matches everywhere");
+                continue;
+            }
+
+            let earlier = &keys[..index];
+            match section {
+                "twistedChars" => self.twisted_char(path, key, earlier),
+                "twistBeginning" => {
+                    if let Some(prefix) = earlier.iter().find(|other| !other.is_empty() && key.starts_with(*other)) {
+                        self.warning(path, &format!("shadowed by \"{}\", which is checked first", prefix));
+                    }
+                }
+                _ => self.ending(path, key),
+            }
+        }
+    }
+
+    fn twisted_char(&mut self, path: &str, key: &str, earlier: &[&str]) {
+        if key.contains(' ') {
+            self.warning(path, "never matches, words don't contain spaces");
+        } else if key.chars().any(char::is_uppercase) {
+            self.warning(path, "never matches, words are lowercased before twisting");
+        } else if let Some(earlier) = earlier.iter().find(|other| !other.is_empty() && key.contains(*other)) {
+            self.warning(path, &format!("shadowed by \"{}\", which is replaced first", earlier));
+        }
+    }
+
+    fn ending(&mut self, path: &str, key: &str) {
+        if punctuation_regex().is_match(key) {
+            self.warning(path, "never matches, punctuation is removed before twisting endings");
+        }
+    }
+}
+
+/// Appends `key` to a JSON path, quoting it if it isn't a plain identifier.
+fn key_path(parent: &str, key: &str) -> String {
+    let is_identifier = !key.is_empty() && key.chars().all(|c| c.is_alphanumeric() || c == '_');
+
+    if is_identifier {
+        format!("{}.{}", parent, key)
+    } else {
+        format!("{}[{:?}]", parent, key)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    mod lint {
+        use crate::{Diagnostic, Dictionary, Severity};
+
+        fn lint(json: &str) -> Vec<(Severity, String)> {
+            Dictionary::lint(json)
+                .into_iter()
+                .map(|Diagnostic { severity, path, .. }| (severity, path))
+                .collect()
+        }
+
+        #[test]
+        fn should_accept_builtin_dictionary_without_errors() {
+            let diagnostics = Dictionary::lint(include_str!("../de-oger.json"));
+
+            assert!(diagnostics.iter().all(|diagnostic| diagnostic.severity == Severity::Warning));
+        }
+
+        #[test]
+        fn should_report_invalid_json() {
+            assert_eq!(lint("{"), vec![(Severity::Error, String::from("$"))]);
+        }
+
+        #[test]
+        fn should_report_empty_pools_and_non_strings() {
+            assert_eq!(
+                lint(r#"{"dot": [], "translations": {"Hallo": ["Meddl", 3]}}"#),
+                vec![
+                    (Severity::Error, String::from("$.dot")),
+                    (Severity::Error, String::from("$.translations.Hallo[1]")),
+                ]
+            );
+        }
+
+        #[test]
+        fn should_report_ignored_translations() {
+            assert_eq!(
+                lint(r#"{"ignored": ["den", "den"], "translations": {"den": ["dn"]}}"#),
+                vec![
+                    (Severity::Warning, String::from("$.ignored[0]")),
+                    (Severity::Warning, String::from("$.ignored[1]")),
+                    (Severity::Warning, String::from("$.ignored[1]")),
+                ]
+            );
+        }
+
+        #[test]
+        fn should_report_invalid_ignored_patterns() {
+            assert_eq!(
+                lint(r#"{"ignored": ["Meddl*", "/(/"], "ignoreCase": "yes"}"#),
+                vec![
+                    (Severity::Error, String::from("$.ignoreCase")),
+                    (Severity::Error, String::from("$.ignored[1]")),
+                ]
+            );
+        }
+
+        #[test]
+        fn should_report_shadowed_rules() {
+            assert_eq!(
+                lint(r#"{"twistedChars": {"t": "d", "tz": "ds", "T": "D", "eug ": "euch"}, "twistBeginning": {"s": "sch", "sp": "schb"}}"#),
+                vec![
+                    (Severity::Warning, String::from("$.twistBeginning.sp")),
+                    (Severity::Warning, String::from("$.twistedChars.T")),
+                    (Severity::Warning, String::from("$.twistedChars[\"eug \"]")),
+                    (Severity::Warning, String::from("$.twistedChars.tz")),
+                ]
+            );
+        }
+
+        #[test]
+        fn should_check_ordered_lists_in_listed_order() {
+            assert_eq!(
+                lint(r#"{"version": 2, "twistedChars": [["tz", "ds"], ["t", "d"], ["tel", "dl"], ["x"]]}"#),
+                vec![
+                    (Severity::Error, String::from("$.twistedChars[3]")),
+                    (Severity::Warning, String::from("$.twistedChars[2]")),
+                ]
+            );
+        }
+
+        #[test]
+        fn should_report_unknown_versions() {
+            assert_eq!(lint(r#"{"version": 7}"#), vec![(Severity::Error, String::from("$.version"))]);
+        }
+
+        #[test]
+        fn should_report_broken_rules() {
+            assert_eq!(
+                lint(r#"{"rules": [{"pattern": "(", "replacement": ""}, {"pattern": "a", "conditions": ["verb"]}, {"pattern": "b", "replacement": "c", "when": []}]}"#),
+                vec![
+                    (Severity::Error, String::from("$.rules[0].pattern")),
+                    (Severity::Error, String::from("$.rules[1]")),
+                    (Severity::Error, String::from("$.rules[1].conditions[0]")),
+                    (Severity::Error, String::from("$.rules[2].when")),
+                ]
+            );
+        }
+
+        #[test]
+        fn should_check_fillers() {
+            assert_eq!(
+                lint(r#"{"openers": {"probability": 2, "phrases": []}, "closers": {"phrases": ["gell"], "where": "end"}, "interjections": []}"#),
+                vec![
+                    (Severity::Error, String::from("$.closers.where")),
+                    (Severity::Warning, String::from("$.closers")),
+                    (Severity::Error, String::from("$.interjections")),
+                    (Severity::Error, String::from("$.openers.phrases")),
+                    (Severity::Error, String::from("$.openers.probability")),
+                ]
+            );
+        }
+
+        #[test]
+        fn should_report_uppercase_pronunciations() {
+            assert_eq!(
+                lint(r#"{"pronunciations": {"Meddl": "mɛdl̩", "oida": 1}}"#),
+                vec![
+                    (Severity::Warning, String::from("$.pronunciations.Meddl")),
+                    (Severity::Error, String::from("$.pronunciations.oida")),
+                ]
+            );
+        }
+
+        #[test]
+        fn should_report_dead_endings() {
+            assert_eq!(
+                lint(r#"{"en": {"en": "ne", "en!": "ne!", "nen": "nene", "": "x"}}"#),
+                vec![
+                    (Severity::Error, String::from("$.en[\"\"]")),
+                    (Severity::Warning, String::from("$.en[\"en!\"]")),
+                ]
+            );
+        }
+
+        #[test]
+        fn should_check_ending_options() {
+            assert_eq!(
+                lint(r#"{"version": 2, "en": [{"suffix": "en", "replacement": "ne", "minStem": -1, "except": "den"}, {"suffix": "en"}]}"#),
+                vec![
+                    (Severity::Error, String::from("$.en[0].except")),
+                    (Severity::Error, String::from("$.en[0].minStem")),
+                    (Severity::Error, String::from("$.en[1]")),
+                ]
+            );
+        }
+    }
+}
@@ -0,0 +1,137 @@
+// This file is also compiled by the build script, keep it free of crate imports.
+
+use std::collections::HashMap;
+
+use serde::Deserialize;
+
+/// The dictionary exactly as it is written in the JSON file, in the newest version.
+///
+/// Sections whose entries are applied one after the other (`twistedChars`, `twistBeginning`
+/// and `en`) are lists of `[from, to]` pairs, kept in the order they are applied in.
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub(crate) struct RawDictionary {
+    pub(crate) version: Option<u64>,
+    pub(crate) translations: HashMap<String, Vec<String>>,
+    pub(crate) question_mark: Vec<String>,
+    pub(crate) exclamation_mark: Vec<String>,
+    pub(crate) dot: Vec<String>,
+    pub(crate) twisted_chars: Vec<(String, String)>,
+    pub(crate) twist_beginning: Vec<(String, String)>,
+    pub(crate) quotation_mark: Option<String>,
+    pub(crate) interlude: String,
+    pub(crate) en: Vec<RawEnding>,
+    pub(crate) ignored: Vec<String>,
+    pub(crate) ignore_case: bool,
+    pub(crate) rules: Vec<RawRule>,
+    pub(crate) openers: RawFillers,
+    pub(crate) interjections: RawFillers,
+    pub(crate) closers: RawFillers,
+    pub(crate) numbers: Vec<String>,
+    pub(crate) pronunciations: HashMap<String, String>,
+}
+
+impl RawDictionary {
+    /// Number of words of the longest translation key, at least 1.
+    ///
+    /// Spaces around a key don't make it a phrase, "euro " is a single word.
+    pub(crate) fn longest_phrase(&self) -> usize {
+        self.translations
+            .keys()
+            .map(|key| key.split(' ').filter(|word| !word.is_empty()).count())
+            .max()
+            .unwrap_or(1)
+            .max(1)
+    }
+}
+
+/// The regular expression of an `ignored` entry wrapped in slashes, like `/^[A-Z]+$/`.
+pub(crate) fn ignored_regex(entry: &str) -> Option<&str> {
+    if entry.len() > 1 && entry.starts_with('/') && entry.ends_with('/') {
+        return Some(&entry[1..entry.len() - 1]);
+    }
+
+    None
+}
+
+/// Whether an `ignored` entry is a regular expression or a glob rather than a plain word.
+pub(crate) fn is_ignored_pattern(entry: &str) -> bool {
+    ignored_regex(entry).is_some() || entry.contains(['*', '?'])
+}
+
+/// An `en` entry, either a `[suffix, replacement]` pair or an object with further options.
+#[derive(Debug, Deserialize)]
+#[serde(from = "EndingFormat")]
+pub(crate) struct RawEnding {
+    pub(crate) suffix: String,
+    pub(crate) replacement: String,
+    pub(crate) min_stem: usize,
+    pub(crate) except: Vec<String>,
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum EndingFormat {
+    Pair(String, String),
+    #[serde(rename_all = "camelCase")]
+    Object {
+        suffix: String,
+        replacement: String,
+        #[serde(default)]
+        min_stem: usize,
+        #[serde(default)]
+        except: Vec<String>,
+    },
+}
+
+impl From<EndingFormat> for RawEnding {
+    fn from(format: EndingFormat) -> RawEnding {
+        match format {
+            EndingFormat::Pair(suffix, replacement) => RawEnding {
+                suffix,
+                replacement,
+                min_stem: 0,
+                except: Vec::new(),
+            },
+            EndingFormat::Object {
+                suffix,
+                replacement,
+                min_stem,
+                except,
+            } => RawEnding {
+                suffix,
+                replacement,
+                min_stem,
+                except,
+            },
+        }
+    }
+}
+
+/// The `openers`, `interjections` or `closers` section, phrases inserted with `probability`.
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub(crate) struct RawFillers {
+    pub(crate) probability: f64,
+    pub(crate) phrases: Vec<String>,
+}
+
+/// A `rules` entry, replacing every match of `pattern` in a word.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub(crate) struct RawRule {
+    pub(crate) pattern: String,
+    pub(crate) replacement: String,
+    #[serde(default)]
+    pub(crate) conditions: Vec<Condition>,
+}
+
+/// When a rule applies, all conditions of a rule have to be met.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) enum Condition {
+    /// The original word is capitalised.
+    Noun,
+    /// The word is the first one of the text or follows a `.`, `!` or `?`.
+    SentenceStart,
+}
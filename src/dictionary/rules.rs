@@ -0,0 +1,157 @@
+use std::borrow::Cow;
+use std::sync::OnceLock;
+
+use regex::Regex;
+
+use crate::dictionary::{Condition, RawRule, Text};
+use crate::Error;
+
+/// A rule as it is written in the dictionary, compiled on first use.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct RuleSource {
+    pub(crate) pattern: Text,
+    pub(crate) replacement: Text,
+    pub(crate) conditions: Cow<'static, [Condition]>,
+}
+
+impl From<RawRule> for RuleSource {
+    fn from(raw: RawRule) -> RuleSource {
+        RuleSource {
+            pattern: Cow::Owned(raw.pattern),
+            replacement: Cow::Owned(raw.replacement),
+            conditions: Cow::Owned(raw.conditions),
+        }
+    }
+}
+
+/// Where in the text a word is, for checking the [`Condition`]s of a rule.
+#[derive(Debug, Clone, Copy, Default)]
+pub(crate) struct Context {
+    pub(crate) is_noun: bool,
+    pub(crate) sentence_start: bool,
+}
+
+impl Context {
+    fn meets(&self, condition: Condition) -> bool {
+        match condition {
+            Condition::Noun => self.is_noun,
+            Condition::SentenceStart => self.sentence_start,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Rule {
+    regex: Regex,
+    replacement: Text,
+    conditions: Cow<'static, [Condition]>,
+}
+
+/// The `rules` section, applied in the order they are listed after all other rules.
+#[derive(Debug, Clone)]
+pub(crate) struct Rules {
+    pub(crate) sources: Cow<'static, [RuleSource]>,
+    compiled: OnceLock<Vec<Rule>>,
+}
+
+impl Rules {
+    pub(crate) const fn new(sources: Cow<'static, [RuleSource]>) -> Rules {
+        Rules {
+            sources,
+            compiled: OnceLock::new(),
+        }
+    }
+
+    /// Compiles all patterns now, instead of panicking when translating.
+    pub(crate) fn compile(&self) -> Result<(), Error> {
+        if self.compiled.get().is_some() {
+            return Ok(());
+        }
+
+        let rules = self
+            .sources
+            .iter()
+            .map(|source| {
+                Ok(Rule {
+                    regex: Regex::new(&source.pattern).map_err(|error| Error::InvalidDictionary(error.to_string()))?,
+                    replacement: source.replacement.clone(),
+                    conditions: source.conditions.clone(),
+                })
+            })
+            .collect::<Result<Vec<Rule>, Error>>()?;
+        let _ = self.compiled.set(rules);
+
+        Ok(())
+    }
+
+    #[cfg(test)]
+    pub(crate) fn apply(&self, word: &str, context: Context) -> String {
+        self.apply_with(word, context, &mut |_| {})
+    }
+
+    /// Applies the rules to `word`, calling `fired` with the index of every rule that matched.
+    pub(crate) fn apply_with(&self, word: &str, context: Context, fired: &mut dyn FnMut(usize)) -> String {
+        self.compile().expect("Could not compile dictionary rules.");
+        let mut word = String::from(word);
+
+        for (index, rule) in self.compiled.get().into_iter().flatten().enumerate() {
+            if rule.conditions.iter().all(|&condition| context.meets(condition)) && rule.regex.is_match(&word) {
+                word = rule.regex.replace_all(&word, rule.replacement.as_ref()).into_owned();
+                fired(index);
+            }
+        }
+
+        word
+    }
+
+    pub(crate) fn len(&self) -> usize {
+        self.sources.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    mod apply {
+        use crate::dictionary::rules::Context;
+        use crate::Dictionary;
+
+        fn dictionary() -> Dictionary {
+            Dictionary::from_json(
+                r#"{"rules": [
+                    {"pattern": "^(\\w+)ung$", "replacement": "${1}ungg"},
+                    {"pattern": "\\bich\\b", "replacement": "i", "conditions": ["sentenceStart"]},
+                    {"pattern": "en$", "replacement": "n", "conditions": ["noun"]}
+                ]}"#,
+            )
+            .unwrap()
+        }
+
+        #[test]
+        fn should_replace_capture_groups() {
+            assert_eq!(dictionary().rules.apply("bedeutung", Context::default()), "bedeutungg");
+        }
+
+        #[test]
+        fn should_check_conditions() {
+            let dictionary = dictionary();
+            let noun = Context {
+                is_noun: true,
+                sentence_start: false,
+            };
+            let sentence_start = Context {
+                is_noun: false,
+                sentence_start: true,
+            };
+
+            assert_eq!(dictionary.rules.apply("ich", Context::default()), "ich");
+            assert_eq!(dictionary.rules.apply("ich", sentence_start), "i");
+            assert_eq!(dictionary.rules.apply("wagen", sentence_start), "wagen");
+            assert_eq!(dictionary.rules.apply("wagen", noun), "wagn");
+        }
+
+        #[test]
+        fn should_reject_invalid_patterns() {
+            assert!(Dictionary::from_json(r#"{"rules": [{"pattern": "(", "replacement": ""}]}"#).is_err());
+        }
+    }
+}
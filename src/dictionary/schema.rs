@@ -0,0 +1,213 @@
+use std::convert::TryFrom;
+
+use serde_json::{Map, Value};
+
+use crate::{Dictionary, Error};
+
+/// Sections whose entries are applied one after the other.
+pub(crate) const PAIRS: [&str; 3] = ["twistedChars", "twistBeginning", "en"];
+
+const SCHEMAS: [&str; 2] = [
+    include_str!("../../schemas/dictionary-v1.schema.json"),
+    include_str!("../../schemas/dictionary-v2.schema.json"),
+];
+
+/// Upgrades a dictionary from the version at the same index plus one to the next version.
+const MIGRATIONS: [fn(&mut Map<String, Value>); 1] = [pairs_to_lists];
+
+impl Dictionary {
+    /// The newest version of the dictionary format, dictionaries without a `version` are version 1.
+    pub const VERSION: u64 = MIGRATIONS.len() as u64 + 1;
+
+    /// The JSON Schema describing `version` of the dictionary format.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meddl_translate::Dictionary;
+    ///
+    /// assert!(Dictionary::schema(Dictionary::VERSION).is_some());
+    /// assert!(Dictionary::schema(0).is_none());
+    /// ```
+    pub fn schema(version: u64) -> Option<&'static str> {
+        let index = usize::try_from(version).ok()?.checked_sub(1)?;
+
+        SCHEMAS.get(index).copied()
+    }
+
+    /// Upgrades a JSON dictionary to [`Dictionary::VERSION`].
+    ///
+    /// Loading a dictionary migrates it as well, this is for updating the file itself.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meddl_translate::Dictionary;
+    ///
+    /// let migrated = Dictionary::migrate(r#"{"en": {"en": "ne"}}"#).unwrap();
+    ///
+    /// assert!(migrated.contains(r#""version": 2"#));
+    /// ```
+    pub fn migrate(json: &str) -> Result<String, Error> {
+        let mut migrated = migrate(serde_json::from_str(json)?)?;
+        let version = migrated.remove("version").unwrap();
+
+        // the version goes first, like in `de-oger.json`.
+        let mut json = format!("{{\n  \"version\": {}", version);
+        for (section, value) in &migrated {
+            json.push_str(&format!(",\n  {}: ", Value::from(section.as_str())));
+            write_pretty(&mut json, value, 2, PAIRS.contains(&section.as_str()));
+        }
+        json.push_str("\n}");
+
+        Ok(json)
+    }
+}
+
+/// The version a dictionary claims to have, `None` if it isn't a known version.
+pub(crate) fn version(root: &Map<String, Value>) -> Option<u64> {
+    match root.get("version") {
+        None => Some(1),
+        Some(version) => version
+            .as_u64()
+            .filter(|version| (1..=Dictionary::VERSION).contains(version)),
+    }
+}
+
+/// Upgrades a dictionary of any known version to [`Dictionary::VERSION`].
+pub(crate) fn migrate(dictionary: Value) -> Result<Map<String, Value>, Error> {
+    let mut root = match dictionary {
+        Value::Object(root) => root,
+        _ => return Err(Error::InvalidDictionary(String::from("a dictionary must be an object"))),
+    };
+    let version = version(&root).ok_or_else(|| {
+        Error::InvalidDictionary(format!(
+            "unsupported version {}, the newest is {}",
+            root["version"],
+            Dictionary::VERSION
+        ))
+    })?;
+
+    for migration in &MIGRATIONS[version as usize - 1..] {
+        migration(&mut root);
+    }
+    root.insert(String::from("version"), Value::from(Dictionary::VERSION));
+
+    Ok(root)
+}
+
+/// Version 2 turns the objects of ordered sections into lists of `[from, to]` pairs, keeping
+/// the sorted key order they were applied in.
+fn pairs_to_lists(root: &mut Map<String, Value>) {
+    for section in PAIRS.iter() {
+        if let Some(Value::Object(pairs)) = root.get(*section) {
+            let pairs = pairs
+                .iter()
+                .map(|(from, to)| Value::Array(vec![Value::from(from.as_str()), to.clone()]))
+                .collect();
+            root.insert(String::from(*section), Value::Array(pairs));
+        }
+    }
+}
+
+/// Writes `value` as indented JSON, with the entries of `pairs` sections on one line each.
+fn write_pretty(json: &mut String, value: &Value, indent: usize, pairs: bool) {
+    let inner = " ".repeat(indent + 2);
+
+    match value {
+        Value::Object(object) if !object.is_empty() => {
+            json.push_str("{\n");
+            for (index, (key, value)) in object.iter().enumerate() {
+                if index > 0 {
+                    json.push_str(",\n");
+                }
+                json.push_str(&format!("{}{}: ", inner, Value::from(key.as_str())));
+                write_pretty(json, value, indent + 2, false);
+            }
+            json.push_str(&format!("\n{}}}", " ".repeat(indent)));
+        }
+        Value::Array(array) if !array.is_empty() => {
+            json.push_str("[\n");
+            for (index, value) in array.iter().enumerate() {
+                if index > 0 {
+                    json.push_str(",\n");
+                }
+                json.push_str(&inner);
+                match value.as_array() {
+                    Some(pair) if pairs => {
+                        let pair: Vec<String> = pair.iter().map(Value::to_string).collect();
+                        json.push_str(&format!("[{}]", pair.join(", ")));
+                    }
+                    _ => write_pretty(json, value, indent + 2, false),
+                }
+            }
+            json.push_str(&format!("\n{}]", " ".repeat(indent)));
+        }
+        value => json.push_str(&value.to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    mod migrate {
+        use serde_json::{json, Value};
+
+        use crate::dictionary::schema;
+
+        fn migrate(dictionary: Value) -> Result<Value, crate::Error> {
+            schema::migrate(dictionary).map(Value::Object)
+        }
+
+        #[test]
+        fn should_turn_ordered_sections_into_lists() {
+            let unversioned =
+                json!({"en": {"nen": "nene", "en": "ne"}, "twistBeginning": {"sp": "schb"}, "dot": ["."]});
+
+            assert_eq!(
+                migrate(unversioned).unwrap(),
+                json!({"version": 2, "en": [["en", "ne"], ["nen", "nene"]], "twistBeginning": [["sp", "schb"]], "dot": ["."]})
+            );
+        }
+
+        #[test]
+        fn should_keep_current_dictionaries() {
+            let current = json!({"version": 2, "en": [["nen", "nene"], ["en", "ne"]]});
+
+            assert_eq!(migrate(current.clone()).unwrap(), current);
+        }
+
+        #[test]
+        fn should_reject_unknown_versions() {
+            assert!(migrate(json!({"version": 3})).is_err());
+            assert!(migrate(json!({"version": "2"})).is_err());
+        }
+    }
+
+    mod migrate_json {
+        use crate::Dictionary;
+
+        #[test]
+        fn should_put_the_version_first_and_pairs_on_one_line() {
+            assert_eq!(
+                Dictionary::migrate(r#"{"en": {"en": "ne"}, "dot": [".", "!"]}"#).unwrap(),
+                "{\n  \"version\": 2,\n  \"dot\": [\n    \".\",\n    \"!\"\n  ],\n  \"en\": [\n    [\"en\", \"ne\"]\n  ]\n}"
+            );
+        }
+    }
+
+    mod schema {
+        use serde_json::Value;
+
+        use crate::Dictionary;
+
+        #[test]
+        fn should_describe_every_section_of_the_builtin_dictionary() {
+            let schema: Value = serde_json::from_str(Dictionary::schema(Dictionary::VERSION).unwrap()).unwrap();
+            let builtin: Value = serde_json::from_str(include_str!("../de-oger.json")).unwrap();
+
+            for section in builtin.as_object().unwrap().keys() {
+                assert!(schema["properties"].get(section).is_some(), "{} is missing", section);
+            }
+        }
+    }
+}
@@ -0,0 +1,90 @@
+use std::fmt;
+
+/// Everything that can go wrong when translating structured input.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum Error {
+    /// The input is not valid JSON.
+    Json(serde_json::Error),
+    /// The input is not valid TOML or doesn't match the dictionary format.
+    #[cfg(feature = "toml")]
+    Toml(toml::de::Error),
+    /// The input is not valid YAML or doesn't match the dictionary format.
+    #[cfg(feature = "yaml")]
+    Yaml(serde_yaml::Error),
+    /// A row of a CSV or TSV word list could not be read.
+    #[cfg(feature = "csv")]
+    Csv(csv::Error),
+    /// A placeholder, select or plural construct did not survive the translation.
+    PlaceholderMismatch { original: String, translated: String },
+    /// The dictionary is valid JSON, but doesn't have the expected structure.
+    InvalidDictionary(String),
+    /// There is no built-in profile with this name.
+    UnknownProfile(String),
+    /// A patch was asked for an absolute path, which it couldn't be applied to.
+    AbsolutePath(String),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Json(error) => write!(f, "invalid JSON: {}", error),
+            #[cfg(feature = "toml")]
+            Error::Toml(error) => write!(f, "invalid TOML: {}", error),
+            #[cfg(feature = "yaml")]
+            Error::Yaml(error) => write!(f, "invalid YAML: {}", error),
+            #[cfg(feature = "csv")]
+            Error::Csv(error) => write!(f, "invalid CSV: {}", error),
+            Error::PlaceholderMismatch { original, translated } => write!(
+                f,
+                "placeholders of \"{}\" changed in translation \"{}\"",
+                original, translated
+            ),
+            Error::InvalidDictionary(message) => write!(f, "invalid dictionary: {}", message),
+            Error::UnknownProfile(name) => write!(f, "unknown profile \"{}\"", name),
+            Error::AbsolutePath(path) => write!(f, "\"{}\" has to be relative to where the patch is applied", path),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Json(error) => Some(error),
+            #[cfg(feature = "toml")]
+            Error::Toml(error) => Some(error),
+            #[cfg(feature = "yaml")]
+            Error::Yaml(error) => Some(error),
+            #[cfg(feature = "csv")]
+            Error::Csv(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<serde_json::Error> for Error {
+    fn from(error: serde_json::Error) -> Self {
+        Error::Json(error)
+    }
+}
+
+#[cfg(feature = "toml")]
+impl From<toml::de::Error> for Error {
+    fn from(error: toml::de::Error) -> Self {
+        Error::Toml(error)
+    }
+}
+
+#[cfg(feature = "yaml")]
+impl From<serde_yaml::Error> for Error {
+    fn from(error: serde_yaml::Error) -> Self {
+        Error::Yaml(error)
+    }
+}
+
+#[cfg(feature = "csv")]
+impl From<csv::Error> for Error {
+    fn from(error: csv::Error) -> Self {
+        Error::Csv(error)
+    }
+}
@@ -0,0 +1,167 @@
+//! C API of the translator, available with the `ffi` feature.
+//!
+//! The `meddl_translate_ffi` package in `ffi` builds the C library exporting them
+//! (`cargo build --release --package meddl_translate_ffi`), `include/meddl_translate.h`
+//! declares the functions below. Strings are passed as
+//! NUL-terminated UTF-8. Functions that can fail return `NULL` or `false` and leave a message
+//! for [`meddl_last_error`].
+//!
+//! ```c
+//! MeddlTranslator *translator = meddl_translator_new();
+//! char *translated = meddl_translate(translator, "Hallo Welt!");
+//! if (translated == NULL) {
+//!     fprintf(stderr, "%s\n", meddl_last_error());
+//! }
+//! meddl_string_free(translated);
+//! meddl_translator_free(translator);
+//! ```
+
+use std::cell::RefCell;
+use std::ffi::{CStr, CString};
+use std::os::raw::c_char;
+use std::panic::{self, AssertUnwindSafe};
+use std::ptr;
+
+use crate::{Dictionary, Translator};
+
+/// A translator owned by C code, created by one of the `meddl_translator_*` functions and
+/// released with [`meddl_translator_free`].
+pub struct MeddlTranslator(Translator);
+
+thread_local! {
+    static LAST_ERROR: RefCell<Option<CString>> = const { RefCell::new(None) };
+}
+
+/// Remembers `message` for [`meddl_last_error`] and returns `failed`.
+fn fail<T>(failed: T, message: String) -> T {
+    let message = CString::new(message.replace('\0', "")).expect("NUL bytes were removed.");
+    LAST_ERROR.with(|last| *last.borrow_mut() = Some(message));
+
+    failed
+}
+
+/// Runs `f`, turning errors and panics into `failed`, so that no panic unwinds into C.
+fn guard<T>(failed: T, f: impl FnOnce() -> Result<T, String>) -> T {
+    match panic::catch_unwind(AssertUnwindSafe(f)) {
+        Ok(Ok(value)) => value,
+        Ok(Err(message)) => fail(failed, message),
+        Err(_) => fail(failed, String::from("panicked while translating")),
+    }
+}
+
+/// The UTF-8 string at `string`, which may be `NULL`.
+unsafe fn to_str<'a>(string: *const c_char, name: &str) -> Result<&'a str, String> {
+    if string.is_null() {
+        return Err(format!("{} is NULL", name));
+    }
+
+    CStr::from_ptr(string)
+        .to_str()
+        .map_err(|_| format!("{} is not valid UTF-8", name))
+}
+
+fn into_raw(translator: Translator) -> *mut MeddlTranslator {
+    Box::into_raw(Box::new(MeddlTranslator(translator)))
+}
+
+/// Creates a translator using the built-in dictionary.
+#[no_mangle]
+pub extern "C" fn meddl_translator_new() -> *mut MeddlTranslator {
+    into_raw(Translator::new())
+}
+
+/// Creates a translator using the built-in dialect profile called `name`, `NULL` if there is no
+/// such profile.
+///
+/// # Safety
+///
+/// `name` has to be `NULL` or point to a NUL-terminated string.
+#[no_mangle]
+pub unsafe extern "C" fn meddl_translator_with_profile(name: *const c_char) -> *mut MeddlTranslator {
+    guard(ptr::null_mut(), || {
+        let translator = Translator::new()
+            .with_profile(to_str(name, "name")?)
+            .map_err(|error| error.to_string())?;
+
+        Ok(into_raw(translator))
+    })
+}
+
+/// Creates a translator using the dictionary `json`, `NULL` if it isn't a valid dictionary.
+///
+/// # Safety
+///
+/// `json` has to be `NULL` or point to a NUL-terminated string.
+#[no_mangle]
+pub unsafe extern "C" fn meddl_translator_with_dictionary(json: *const c_char) -> *mut MeddlTranslator {
+    guard(ptr::null_mut(), || {
+        let dictionary = Dictionary::from_json(to_str(json, "json")?).map_err(|error| error.to_string())?;
+
+        Ok(into_raw(Translator::new().with_dictionary(dictionary)))
+    })
+}
+
+/// Makes every translation of `translator` deterministic, `false` if `translator` is `NULL`.
+///
+/// # Safety
+///
+/// `translator` has to be `NULL` or a translator that hasn't been freed.
+#[no_mangle]
+pub unsafe extern "C" fn meddl_translator_set_seed(translator: *mut MeddlTranslator, seed: u64) -> bool {
+    match translator.as_mut() {
+        Some(MeddlTranslator(translator)) => {
+            *translator = translator.clone().with_seed(seed);
+            true
+        }
+        None => fail(false, String::from("translator is NULL")),
+    }
+}
+
+/// Translates `text`, `NULL` if an argument is `NULL` or `text` isn't valid UTF-8. The result
+/// has to be released with [`meddl_string_free`].
+///
+/// # Safety
+///
+/// `translator` has to be `NULL` or a translator that hasn't been freed, `text` has to be
+/// `NULL` or point to a NUL-terminated string.
+#[no_mangle]
+pub unsafe extern "C" fn meddl_translate(translator: *const MeddlTranslator, text: *const c_char) -> *mut c_char {
+    guard(ptr::null_mut(), || {
+        let MeddlTranslator(translator) = translator.as_ref().ok_or("translator is NULL")?;
+        let translated = translator.translate(to_str(text, "text")?);
+        let translated = CString::new(translated).map_err(|_| "the translation contains a NUL byte")?;
+
+        Ok(translated.into_raw())
+    })
+}
+
+/// The message of the last error on this thread, `NULL` if nothing failed yet. The message
+/// belongs to the library and stays valid until the next error on this thread.
+#[no_mangle]
+pub extern "C" fn meddl_last_error() -> *const c_char {
+    LAST_ERROR.with(|last| last.borrow().as_ref().map_or(ptr::null(), |message| message.as_ptr()))
+}
+
+/// Releases a string returned by [`meddl_translate`], `NULL` is ignored.
+///
+/// # Safety
+///
+/// `string` has to be `NULL` or a string returned by this library that hasn't been freed.
+#[no_mangle]
+pub unsafe extern "C" fn meddl_string_free(string: *mut c_char) {
+    if !string.is_null() {
+        drop(CString::from_raw(string));
+    }
+}
+
+/// Releases a translator, `NULL` is ignored.
+///
+/// # Safety
+///
+/// `translator` has to be `NULL` or a translator that hasn't been freed.
+#[no_mangle]
+pub unsafe extern "C" fn meddl_translator_free(translator: *mut MeddlTranslator) {
+    if !translator.is_null() {
+        drop(Box::from_raw(translator));
+    }
+}
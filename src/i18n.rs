@@ -0,0 +1,582 @@
+//! Translation of JSON locale files and Fluent (`.ftl`) resources.
+//!
+//! Only the text of a message is translated. Interpolations (`{{var}}`, `{var}`, `{ $var }`),
+//! ICU `plural`/`select` arguments and Fluent select expressions are copied as they are, only
+//! the texts of their variants are translated. Every message is parsed again after the
+//! translation and compared against the original, a message whose placeholders changed is
+//! reported as [`Error::PlaceholderMismatch`].
+//!
+//! ```
+//! use meddl_translate::i18n::translate_json_with;
+//! use meddl_translate::Translator;
+//!
+//! let json = r#"{"inbox": {"title": "{count, plural, one {# Rust} other {# programmiert}}"}}"#;
+//!
+//! assert_eq!(
+//!     translate_json_with(&Translator::new().with_seed(1), json).unwrap(),
+//!     r#"{"inbox": {"title": "{count, plural, one {# Rusd} other {# brogrammierd}}"}}"#
+//! );
+//! ```
+
+use regex::Regex;
+use serde_json::Value;
+
+use crate::placeholders::{placeholder_regex, translate_protected};
+use crate::{Error, Translator};
+
+/// The placeholder syntax used inside a message.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Syntax {
+    /// ICU MessageFormat (`{name}`, `{count, plural, one {...} other {...}}`) as well as
+    /// i18next style `{{name}}` interpolations, used by JSON locale files.
+    Icu,
+    /// Fluent placeables (`{ $name }`, `{ -term }`) and select expressions.
+    Fluent,
+}
+
+/// Translates every string value of a JSON locale file, keys and the formatting of the file are kept.
+///
+/// # Example
+///
+/// ```
+/// let translated = meddl_translate::i18n::translate_json(r#"{"greeting": "Hallo {{name}}"}"#).unwrap();
+/// ```
+pub fn translate_json(json: &str) -> Result<String, Error> {
+    translate_json_with(&Translator::new(), json)
+}
+
+/// Like [`translate_json`], but translates with `translator`, e.g. for using a profile.
+pub fn translate_json_with(translator: &Translator, json: &str) -> Result<String, Error> {
+    serde_json::from_str::<Value>(json)?;
+
+    let placeholders = placeholder_regex();
+    let mut translated = String::new();
+    let mut rest = json;
+
+    while let Some(start) = rest.find('"') {
+        translated.push_str(&rest[..start]);
+        let end = start + string_token_length(&rest[start..]);
+        let token = &rest[start..end];
+        rest = &rest[end..];
+
+        if rest.trim_start().starts_with(':') {
+            translated.push_str(token);
+            continue;
+        }
+
+        let message: String = serde_json::from_str(token)?;
+        let message = translate_checked(&message, Syntax::Icu, placeholders, translator)?;
+        translated.push_str(&serde_json::to_string(&message)?);
+    }
+    translated.push_str(rest);
+
+    Ok(translated)
+}
+
+/// Translates the values and attributes of all messages and terms in a Fluent resource.
+///
+/// # Example
+///
+/// ```
+/// use meddl_translate::i18n::translate_fluent_with;
+/// use meddl_translate::Translator;
+///
+/// let ftl = "emails = { $unread ->\n    [one] Rust\n   *[other] programmiert\n}\n";
+///
+/// assert_eq!(
+///     translate_fluent_with(&Translator::new().with_seed(1), ftl).unwrap(),
+///     "emails = { $unread ->\n    [one] Rusd\n   *[other] brogrammierd\n}\n"
+/// );
+/// ```
+pub fn translate_fluent(ftl: &str) -> Result<String, Error> {
+    translate_fluent_with(&Translator::new(), ftl)
+}
+
+/// Like [`translate_fluent`], but translates with `translator`, e.g. for using a profile.
+pub fn translate_fluent_with(translator: &Translator, ftl: &str) -> Result<String, Error> {
+    let placeholders = placeholder_regex();
+    let mut translated = String::new();
+    let mut value = String::new();
+
+    for line in ftl.split_inclusive('\n') {
+        let is_continuation = !value.is_empty()
+            && line.starts_with([' ', '\t', '}'])
+            && !line.trim_start().starts_with('.');
+
+        if is_continuation {
+            value.push_str(line);
+            continue;
+        }
+        flush_fluent_value(&mut translated, &mut value, placeholders, translator)?;
+
+        match fluent_value_start(line) {
+            Some(start) => {
+                translated.push_str(&line[..start]);
+                value.push_str(&line[start..]);
+            }
+            None => translated.push_str(line),
+        }
+    }
+    flush_fluent_value(&mut translated, &mut value, placeholders, translator)?;
+
+    Ok(translated)
+}
+
+/// Translates a single message, making sure its placeholders survive unchanged.
+///
+/// # Example
+///
+/// ```
+/// use meddl_translate::i18n::{translate_message_with, Syntax};
+/// use meddl_translate::Translator;
+///
+/// let translator = Translator::new().with_seed(1);
+///
+/// assert_eq!(translate_message_with(&translator, "{ $name } Rust", Syntax::Fluent).unwrap(), "{ $name } Rusd");
+/// ```
+pub fn translate_message(message: &str, syntax: Syntax) -> Result<String, Error> {
+    translate_message_with(&Translator::new(), message, syntax)
+}
+
+/// Like [`translate_message`], but translates with `translator`, e.g. for using a profile.
+pub fn translate_message_with(translator: &Translator, message: &str, syntax: Syntax) -> Result<String, Error> {
+    translate_checked(message, syntax, placeholder_regex(), translator)
+}
+
+fn translate_checked(message: &str, syntax: Syntax, placeholders: &Regex, translator: &Translator) -> Result<String, Error> {
+    let (translated, original_tokens) = walk(message, syntax, placeholders, Some(translator));
+    let (_, translated_tokens) = walk(&translated, syntax, placeholders, None);
+
+    if original_tokens != translated_tokens {
+        return Err(Error::PlaceholderMismatch {
+            original: String::from(message),
+            translated,
+        });
+    }
+
+    Ok(translated)
+}
+
+fn flush_fluent_value(
+    translated: &mut String,
+    value: &mut String,
+    placeholders: &Regex,
+    translator: &Translator,
+) -> Result<(), Error> {
+    if value.is_empty() {
+        return Ok(());
+    }
+
+    let content = value.trim_end();
+    let trailing = &value[content.len()..];
+    translated.push_str(&translate_checked(content, Syntax::Fluent, placeholders, translator)?);
+    translated.push_str(trailing);
+    value.clear();
+
+    Ok(())
+}
+
+/// Returns where the value of a message, term or attribute starts in `line`.
+fn fluent_value_start(line: &str) -> Option<usize> {
+    let trimmed = line.trim_start();
+    let indent = line.len() - trimmed.len();
+    let identifier = trimmed
+        .strip_prefix('.')
+        .or_else(|| trimmed.strip_prefix('-'))
+        .unwrap_or(trimmed);
+
+    if indent > 0 && !trimmed.starts_with('.') {
+        return None;
+    }
+    if !identifier.starts_with(|c: char| c.is_ascii_alphabetic()) {
+        return None;
+    }
+
+    let equals = line.find('=')?;
+    let name = line[indent..equals].trim_end();
+    let name_is_identifier = name
+        .trim_start_matches(['.', '-'])
+        .chars()
+        .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-');
+    if !name_is_identifier {
+        return None;
+    }
+
+    let after_equals = &line[equals + 1..];
+
+    // for multiline values starting on the next line the line break becomes part of the value.
+    Some(line.len() - after_equals.trim_start_matches(' ').len())
+}
+
+/// Length of the JSON string token at the start of `json`, including both quotes.
+fn string_token_length(json: &str) -> usize {
+    let mut escaped = false;
+
+    for (index, c) in json.char_indices().skip(1) {
+        match c {
+            '\\' if !escaped => escaped = true,
+            '"' if !escaped => return index + 1,
+            _ => escaped = false,
+        }
+    }
+
+    json.len()
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum Stop {
+    End,
+    Brace,
+    Variant,
+}
+
+struct Walker<'a> {
+    source: &'a str,
+    position: usize,
+    syntax: Syntax,
+    placeholders: &'a Regex,
+    translator: Option<&'a Translator>,
+    output: String,
+    tokens: Vec<String>,
+}
+
+/// Walks through a message, translating (if there is a `translator`) its text and collecting
+/// every placeholder and control structure it passes.
+fn walk(message: &str, syntax: Syntax, placeholders: &Regex, translator: Option<&Translator>) -> (String, Vec<String>) {
+    let mut walker = Walker {
+        source: message,
+        position: 0,
+        syntax,
+        placeholders,
+        translator,
+        output: String::new(),
+        tokens: Vec::new(),
+    };
+    walker.pattern(Stop::End, false);
+
+    (walker.output, walker.tokens)
+}
+
+impl<'a> Walker<'a> {
+    fn rest(&self) -> &'a str {
+        &self.source[self.position..]
+    }
+
+    fn push_text(&mut self, text: &str) {
+        for placeholder in self.placeholders.find_iter(text) {
+            self.tokens.push(String::from(placeholder.as_str()));
+        }
+
+        match self.translator {
+            Some(translator) => self.output.push_str(&translate_protected(text, self.placeholders, translator)),
+            None => self.output.push_str(text),
+        }
+    }
+
+    fn push_token(&mut self, length: usize) {
+        let token = &self.source[self.position..self.position + length];
+        self.output.push_str(token);
+        self.tokens.push(String::from(token));
+        self.position += length;
+    }
+
+    fn push_whitespace(&mut self) {
+        let rest = self.rest();
+        let length = rest.len() - rest.trim_start().len();
+        self.output.push_str(&rest[..length]);
+        self.position += length;
+    }
+
+    fn pattern(&mut self, stop: Stop, hash: bool) {
+        let mut start = self.position;
+
+        while self.position < self.source.len() {
+            let rest = self.rest();
+
+            if stop != Stop::End && rest.starts_with('}') {
+                break;
+            }
+            if stop == Stop::Variant && rest.starts_with('\n') && is_variant_start(rest[1..].trim_start()) {
+                break;
+            }
+            if (hash && rest.starts_with('#')) || rest.starts_with('{') {
+                self.push_text(&self.source[start..self.position]);
+                if rest.starts_with('#') {
+                    self.push_token(1);
+                } else if self.syntax == Syntax::Icu {
+                    self.icu_argument();
+                } else {
+                    self.fluent_placeable();
+                }
+                start = self.position;
+                continue;
+            }
+
+            self.position += rest.chars().next().map_or(1, char::len_utf8);
+        }
+
+        self.push_text(&self.source[start..self.position]);
+    }
+
+    fn icu_argument(&mut self) {
+        let rest = self.rest();
+
+        if rest.starts_with("{{") {
+            let length = rest.find("}}").map_or(rest.len(), |end| end + 2);
+            return self.push_token(length);
+        }
+
+        let header = match rest.find([',', '}']) {
+            Some(header) if rest[header..].starts_with(',') => header,
+            _ => return self.push_token(balanced_length(rest)),
+        };
+        let after_name = &rest[header + 1..];
+        let kind_length = after_name.find([',', '}']).unwrap_or(after_name.len());
+        let kind = after_name[..kind_length].trim();
+        let is_options = ["plural", "select", "selectordinal"].contains(&kind) && after_name[kind_length..].starts_with(',');
+
+        if !is_options {
+            return self.push_token(balanced_length(rest));
+        }
+
+        self.push_token(header + 1 + kind_length + 1);
+        self.icu_options(kind != "select");
+    }
+
+    fn icu_options(&mut self, hash: bool) {
+        loop {
+            self.push_whitespace();
+            let rest = self.rest();
+
+            if rest.is_empty() {
+                return;
+            }
+            if rest.starts_with('}') {
+                return self.push_token(1);
+            }
+            if rest.starts_with('{') {
+                self.push_token(1);
+                self.pattern(Stop::Brace, hash);
+                if self.rest().starts_with('}') {
+                    self.push_token(1);
+                }
+                continue;
+            }
+
+            let selector = rest.find(|c: char| c.is_whitespace() || c == '{' || c == '}').unwrap_or(rest.len());
+            self.push_token(selector);
+        }
+    }
+
+    fn fluent_placeable(&mut self) {
+        let rest = self.rest();
+        let length = balanced_length(rest);
+
+        match select_arrow(&rest[..length]) {
+            Some(arrow) => {
+                self.push_token(arrow + 2);
+                self.fluent_variants();
+            }
+            None => self.push_token(length),
+        }
+    }
+
+    fn fluent_variants(&mut self) {
+        loop {
+            self.push_whitespace();
+            let rest = self.rest();
+
+            if rest.is_empty() {
+                return;
+            }
+            if rest.starts_with('}') {
+                return self.push_token(1);
+            }
+
+            let position = self.position;
+            if is_variant_start(rest) {
+                let key = rest.find(']').map_or(rest.len(), |end| end + 1);
+                self.push_token(key);
+                let rest = self.rest();
+                let spaces = rest.len() - rest.trim_start_matches([' ', '\t']).len();
+                self.output.push_str(&rest[..spaces]);
+                self.position += spaces;
+            }
+            self.pattern(Stop::Variant, false);
+
+            if self.position == position {
+                let length = self.rest().chars().next().map_or(1, char::len_utf8);
+                self.push_token(length);
+            }
+        }
+    }
+}
+
+fn is_variant_start(text: &str) -> bool {
+    text.starts_with('[') || text.starts_with("*[") || text.starts_with('}')
+}
+
+/// Length of the placeable starting at the beginning of `text`, up to and including its closing brace.
+fn balanced_length(text: &str) -> usize {
+    let mut depth = 0;
+    let mut in_string = false;
+    let mut escaped = false;
+
+    for (index, c) in text.char_indices() {
+        if in_string {
+            match c {
+                '\\' if !escaped => escaped = true,
+                '"' if !escaped => in_string = false,
+                _ => escaped = false,
+            }
+            continue;
+        }
+        match c {
+            '"' => in_string = true,
+            '{' => depth += 1,
+            '}' => {
+                depth -= 1;
+                if depth == 0 {
+                    return index + 1;
+                }
+            }
+            _ => {}
+        }
+    }
+
+    text.len()
+}
+
+/// Position of the `->` of a Fluent select expression inside `placeable`.
+fn select_arrow(placeable: &str) -> Option<usize> {
+    let mut depth = 0;
+    let mut in_string = false;
+
+    for (index, c) in placeable.char_indices() {
+        match c {
+            '"' => in_string = !in_string,
+            '{' if !in_string => depth += 1,
+            '}' if !in_string => depth -= 1,
+            '-' if !in_string && depth == 1 && placeable[index..].starts_with("->") => return Some(index),
+            _ => {}
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    mod translate_json {
+        use crate::i18n::translate_json;
+
+        #[test]
+        fn should_translate_string_leaves_only() {
+            let json = "{\n  \"Rust\": \"Rust\",\n  \"nested\": {\"list\": [\"programmiert\", 3, true]}\n}";
+
+            assert_eq!(
+                translate_json(json).unwrap(),
+                "{\n  \"Rust\": \"Rusd\",\n  \"nested\": {\"list\": [\"brogrammierd\", 3, true]}\n}"
+            );
+        }
+
+        #[test]
+        fn should_keep_interpolations() {
+            let json = r#"{"a": "{{name}} Rust", "b": "Rust {name}", "c": "Rust %s"}"#;
+
+            assert_eq!(translate_json(json).unwrap(), r#"{"a": "{{name}} Rusd", "b": "Rusd {name}", "c": "Rusd %s"}"#);
+        }
+
+        #[test]
+        fn should_translate_icu_variants() {
+            let json = r#"{"a": "{gender, select, male {Rust} other {{count, plural, =0 {programmiert} other {# Rust}}}}"}"#;
+
+            assert_eq!(
+                translate_json(json).unwrap(),
+                r#"{"a": "{gender, select, male {Rusd} other {{count, plural, =0 {brogrammierd} other {# Rusd}}}}"}"#
+            );
+        }
+
+        #[test]
+        fn should_reject_invalid_json() {
+            assert!(translate_json("{\"a\": ").is_err());
+        }
+    }
+
+    mod translate_json_with {
+        use crate::i18n::translate_json_with;
+        use crate::{Dictionary, Translator};
+
+        #[test]
+        fn should_translate_with_the_given_translator() {
+            let dictionary = Dictionary::from_json(r#"{"translations": {"ich": ["i"]}}"#).unwrap();
+            let translator = Translator::new().with_dictionary(dictionary);
+
+            assert_eq!(translate_json_with(&translator, r#"{"me": "ich {name}"}"#).unwrap(), r#"{"me": "i {name}"}"#);
+        }
+    }
+
+    mod translate_fluent {
+        use crate::i18n::translate_fluent;
+
+        #[test]
+        fn should_translate_messages_and_attributes() {
+            let ftl = "# Kommentar\nhello = Rust { $name }\n    .title = programmiert\n\n-brand = Rust\n";
+
+            assert_eq!(
+                translate_fluent(ftl).unwrap(),
+                "# Kommentar\nhello = Rusd { $name }\n    .title = brogrammierd\n\n-brand = Rusd\n"
+            );
+        }
+
+        #[test]
+        fn should_translate_multiline_values() {
+            let ftl = "long =\n    Rust\n    programmiert\n";
+
+            assert_eq!(translate_fluent(ftl).unwrap(), "long =\n    Rusd\n    brogrammierd\n");
+        }
+
+        #[test]
+        fn should_keep_select_expressions() {
+            let ftl = "count = { $n ->\n    [one] { $n } Rust\n   *[other] { $n } programmiert\n}\n";
+
+            assert_eq!(
+                translate_fluent(ftl).unwrap(),
+                "count = { $n ->\n    [one] { $n } Rusd\n   *[other] { $n } brogrammierd\n}\n"
+            );
+        }
+    }
+
+    mod translate_fluent_with {
+        use crate::i18n::translate_fluent_with;
+        use crate::{Dictionary, Translator};
+
+        #[test]
+        fn should_translate_with_the_given_translator() {
+            let dictionary = Dictionary::from_json(r#"{"translations": {"ich": ["i"]}}"#).unwrap();
+            let translator = Translator::new().with_dictionary(dictionary);
+
+            assert_eq!(translate_fluent_with(&translator, "me = ich { $name }\n").unwrap(), "me = i { $name }\n");
+        }
+    }
+
+    mod walk {
+        use crate::i18n::{walk, Syntax};
+        use crate::placeholders::placeholder_regex;
+
+        #[test]
+        fn should_collect_placeholders_and_selectors() {
+            let (_, tokens) = walk("{n, plural, one {# Rust} other {{{x}} %s}}", Syntax::Icu, placeholder_regex(), None);
+
+            assert_eq!(tokens, vec!["{n, plural,", "one", "{", "#", "}", "other", "{", "{{x}}", "%s", "}", "}"]);
+        }
+
+        #[test]
+        fn should_detect_changed_placeholders() {
+            let (_, original) = walk("{ $n } Rust", Syntax::Fluent, placeholder_regex(), None);
+            let (_, broken) = walk("$n } Rust", Syntax::Fluent, placeholder_regex(), None);
+
+            assert_ne!(original, broken);
+        }
+    }
+}
@@ -3,9 +3,7 @@
 //! # Usage
 //!
 //! ```rust
-//! fn main() {
-//!     println!("{}", meddl_translate::translate("Hallo"));
-//! }
+//! println!("{}", meddl_translate::translate("Hallo"));
 //! ```
 //!
 //! # Examples
@@ -31,6 +29,147 @@
 //! $ cargo run --example ignored
 //! ```
 //!
+//! Entries containing `*` or `?` are globs, entries wrapped in slashes are regular expressions.
+//! With `"ignoreCase": true` all of them match regardless of case. Words that only need to be
+//! protected in one place can be passed to [`Translator::with_ignored`] instead.
+//!
+//! # Custom dictionaries
+//!
+//! The built-in dictionary is compiled into the binary. Dictionaries using the same format
+//! can be loaded with [`Dictionary::from_json`] and used through [`Translator::with_dictionary`].
+//! [`Dictionary::lint`] reports mistakes in a dictionary, also available on the command line:
+//!
+//! ```shell
+//! $ cargo run --bin meddl -- lint my-dictionary.json
+//! ```
+//!
+//! Instead of copying the whole dictionary, small overlays can be stacked on top of it with
+//! [`LayeredDictionary`], each [`Layer`] appending, replacing or removing entries.
+//!
+//! The format is versioned, dictionaries written for an older version are migrated when
+//! loaded. [`Dictionary::migrate`] upgrades the JSON itself and [`Dictionary::schema`] returns
+//! the JSON Schema of each version.
+//!
+//! Keys of `translations` may also be phrases like `"ich weiß nicht"`. The longest phrase
+//! matching the text is used, its first letter following the case of the original.
+//!
+//! Besides the literal `twistedChars`, `twistBeginning` and `en` rules, a dictionary can have a
+//! `rules` section of regular expressions. They are applied in the listed order to every word
+//! after all other rules, optionally only to nouns or at the start of a sentence:
+//!
+//! ```json
+//! { "rules": [{ "pattern": "^(\\w+)ung$", "replacement": "${1}ungg", "conditions": ["noun"] }] }
+//! ```
+//!
+//! With the `toml`, `yaml` and `csv` features, dictionaries and layers can also be read from
+//! TOML, YAML or a `source,replacement` word list, e.g. `Dictionary::from_yaml`.
+//!
+//! # Aligning the output with the input
+//!
+//! [`translate_tokens`] returns the translation as tokens, each carrying the byte range of the
+//! input it was made from, e.g. for highlighting.
+//!
+//! # Sentences
+//!
+//! Only the punctuation ending a sentence is replaced from the `dot`, `exclamationMark` and
+//! `questionMark` pools, the dots of abbreviations, ordinals and decimals are kept. See the
+//! [`sentence`] module for the segmentation itself.
+//!
+//! The `openers`, `interjections` and `closers` sections hold filler phrases that
+//! [`Translator::with_fillers`] puts in front of sentences, between words and in front of the
+//! punctuation ending a sentence, each with its own probability:
+//!
+//! ```json
+//! { "openers": { "probability": 0.1, "phrases": ["Meddl Leude!"] } }
+//! ```
+//!
+//! # Numbers
+//!
+//! Numbers, decimals, times and dates are kept as they are, together with their punctuation.
+//! [`Translator::with_number_words`] writes whole numbers in words from the `numbers` section,
+//! e.g. `"numbers": ["null", "aans", "zwaa"]`.
+//!
+//! # Umlauts
+//!
+//! Words are looked up in Unicode normalisation form C, so decomposed umlauts still match.
+//! [`Translator::with_transliteration`] also matches words written with "ae", "oe", "ue" or
+//! "ss" instead of umlauts and ß, keeping that spelling in the translation.
+//!
+//! # Pronunciation
+//!
+//! With the `phonetic` feature, the [`phonetic`] module transcribes translated text to IPA or
+//! SAMPA. Words listed in the `pronunciations` section of the dictionary override the rules:
+//!
+//! ```json
+//! { "pronunciations": { "meddl": "mɛdl̩" } }
+//! ```
+//!
+//! The expected transcriptions are kept as golden files in `tests/golden/phonetic`, running the
+//! tests with `UPDATE_GOLDEN=1` rewrites them.
+//!
+//! # Speech synthesis
+//!
+//! [`Translator::translate_ssml`] renders the translation as SSML for engines like espeak or
+//! piper, see [`ssml`]. Sentences are wrapped in `<s>`, interludes become pauses and stage
+//! directions like `*ferstellt Stimme:*` change the prosody of the rest of the sentence.
+//!
+//! # Detecting dialect
+//!
+//! [`analysis::analyze`] scores from 0 to 1 how much of a text is already in dialect, counting
+//! replacement forms like "Loide", twisted chars like "schb" and suffix phrases like
+//! "des bascht scho", and returns the matches as evidence.
+//!
+//! # Coverage statistics
+//!
+//! [`stats::stats`] counts which words of a corpus are found in the translations, which are
+//! only twisted by the rules, which are left untranslated and how often every rule fired, as
+//! JSON or as a table. [`suggest`] turns the untranslated words into a patch adding them to
+//! the `translations` of a dictionary file, ready for review.
+//!
+//! # Dialect profiles
+//!
+//! Besides the default `"oger"` dictionary, Franconian, Swabian and Bavarian flavoured
+//! profiles are built in. [`Dictionary::profiles`] lists them and [`Translator::with_profile`]
+//! selects one:
+//!
+//! ```
+//! let translator = meddl_translate::Translator::new().with_profile("franconian").unwrap();
+//! ```
+//!
+//! # Deterministic and parallel translation
+//!
+//! A [`Translator`] with a seed always gives the same result for the same input. With the
+//! `parallel` feature, [`Translator::translate_parallel`] translates large inputs on all cores
+//! using rayon, with the same result as the sequential translation.
+//!
+//! ```toml
+//! meddl_translate = { version = "*", features = ["parallel"] }
+//! ```
+//!
+//! # Translating gettext catalogues
+//!
+//! See the [`po`] module for translating whole `.po` files, placeholders like `%s` or
+//! `{name}` are kept intact.
+//!
+//! # Translating JSON and Fluent resources
+//!
+//! The [`i18n`] module translates nested JSON locale files and Fluent `.ftl` resources,
+//! interpolations and plural/select constructs are verified to survive the translation.
+//!
+//! # Calling from C
+//!
+//! With the `ffi` feature, `cargo rustc --lib --features ffi --crate-type cdylib` builds a C
+//! library exporting the C API of the [`ffi`] module, declared in `include/meddl_translate.h`.
+//!
+//! # Translating large inputs
+//!
+//! [`stream::translate_stream`] translates anything implementing `BufRead` into anything
+//! implementing `Write` without loading the whole input into memory.
+//!
+//! ```shell
+//! $ cargo run --example stdin < book.txt
+//! ```
+//!
 //! # Benchmark
 //!
 //! ```shell
@@ -39,18 +178,62 @@
 //!
 //! You need to use Rust nightly for running the benchmark.
 
+mod dictionary;
+mod error;
+mod normalize;
+mod number;
+mod placeholders;
+mod token;
+mod translator;
 mod util;
 
-use serde_json::Value;
+pub mod analysis;
+#[cfg(feature = "ffi")]
+pub mod ffi;
+pub mod i18n;
+#[cfg(feature = "phonetic")]
+pub mod phonetic;
+pub mod po;
+pub mod sentence;
+pub mod ssml;
+pub mod stats;
+pub mod stream;
+pub mod suggest;
+
+pub use dictionary::{Diagnostic, Dictionary, Layer, LayeredDictionary, Severity, Strategy};
+pub use error::Error;
+pub use token::{Token, TokenKind};
+pub use translator::Translator;
+
+use std::cmp::Reverse;
+use std::collections::HashSet;
+use std::ops::Range;
+use std::sync::OnceLock;
+
+use dictionary::{Context, Text};
+use translator::Options;
+use rand::RngCore;
 use regex::{Regex};
 #[cfg(feature = "interlude")]
-use util::{is_ignored_word, get_random_index, is_one_percent_chance, capitalize_word};
+use util::{get_random_index, is_one_percent_chance, capitalize_word, lowercase_first};
 #[cfg(not(feature = "interlude"))]
-use util::{is_ignored_word, get_random_index, capitalize_word};
+use util::{get_random_index, capitalize_word, lowercase_first};
 
-fn parse_translation() -> Option<Value> {
-    let translation_string = include_str!("de-oger.json");
-    Some(serde_json::from_str(&translation_string).expect("Could not parse translation."))
+const PUNCTUATION_CLASS: &str = r"[.,\\/#!?$%\^&\*;:{}=\-_`~()]";
+
+fn punctuation_regex() -> &'static Regex {
+    static PUNCTUATION: OnceLock<Regex> = OnceLock::new();
+
+    PUNCTUATION.get_or_init(|| Regex::new(PUNCTUATION_CLASS).expect("Could not compile punctuation regex."))
+}
+
+/// Matches the punctuation a word ends with, e.g. the `...` of `Ende...`.
+fn trailing_punctuation_regex() -> &'static Regex {
+    static TRAILING_PUNCTUATION: OnceLock<Regex> = OnceLock::new();
+
+    TRAILING_PUNCTUATION.get_or_init(|| {
+        Regex::new(&format!("{}+$", PUNCTUATION_CLASS)).expect("Could not compile punctuation regex.")
+    })
 }
 
 /// This function translates a string slice from German to Meddlfrängisch.
@@ -58,197 +241,449 @@ fn parse_translation() -> Option<Value> {
 /// # Example
 ///
 /// ```
-/// fn main() {
-///     let meddl_fraengisch = meddl_translate::translate("Hallo Welt");
-/// }
+/// let meddl_fraengisch = meddl_translate::translate("Hallo Welt");
 /// ```
 pub fn translate(original: &str) -> String {
-    if original.len() == 0 {
-        return String::from("");
+    Translator::new().translate(original)
+}
+
+/// This function translates a string slice from German to Meddlfrängisch and returns the
+/// translation as a list of tokens, each knowing the part of `original` it was made from.
+///
+/// Concatenating the text of all tokens gives the same result as [`translate`]. Whitespace
+/// at the very beginning and end of `original` is dropped.
+///
+/// # Example
+///
+/// ```
+/// use meddl_translate::{TokenKind, Translator};
+///
+/// let tokens = Translator::new().with_seed(1).translate_tokens("Rust programmiert");
+///
+/// assert_eq!(tokens[0].span, 0..4);
+/// assert_eq!(tokens[0].text, "Rusd");
+/// assert_eq!(tokens[1].kind, TokenKind::Whitespace);
+/// assert_eq!(tokens.iter().map(|token| token.text.as_str()).collect::<String>(), "Rusd brogrammierd");
+/// ```
+pub fn translate_tokens(original: &str) -> Vec<Token> {
+    Translator::new().translate_tokens(original)
+}
+
+/// What happened to a word in [`translate_chunk`], reported to its hook. The words are
+/// passed the way they appear in the original, without the punctuation around them.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum Step<'a> {
+    /// The word, or the phrase, was found in the translations.
+    Translated(&'a str),
+    /// The word is ignored and was kept as it is.
+    Ignored(&'a str),
+    /// The word was changed by beginnings, twisted chars, endings or rules only.
+    Twisted(&'a str),
+    /// The word came out the way it went in, apart from its case.
+    Untouched(&'a str),
+    /// The rule at this index of the dictionary matched.
+    Rule(usize),
+}
+
+impl<'a> Step<'a> {
+    /// The same step for `word`, rules stay as they are.
+    fn with_word(self, word: &str) -> Step<'_> {
+        match self {
+            Step::Translated(_) => Step::Translated(word),
+            Step::Ignored(_) => Step::Ignored(word),
+            Step::Twisted(_) => Step::Twisted(word),
+            Step::Untouched(_) => Step::Untouched(word),
+            Step::Rule(index) => Step::Rule(index),
+        }
     }
+}
 
-    let words: Vec<&str> = original.split(" ").collect();
-    let translation: Value = parse_translation().unwrap();
-    let punctuation_regex = Regex::new(r"[.,\\/#!?$%\^&\*;:{}=\-_`~()]").expect("Could not compile punctuation regex.");
-    let mut meddl = String::new();
+fn translate_chunk(
+    original: &str,
+    translation: &Dictionary,
+    options: Options,
+    rng: &mut dyn RngCore,
+    hook: &mut dyn FnMut(Step),
+) -> Vec<Token> {
+    let punctuation_regex = punctuation_regex();
+    let whitespace = |end: usize| {
+        let span = end..end + original[end..].chars().next()?.len_utf8();
+        Some(Token::new(span.clone(), &original[span], TokenKind::Whitespace))
+    };
+    // words are split like sentences are, at any whitespace.
+    let mut words = Vec::new();
+    let mut offset = 0;
+    for (index, separator) in original.match_indices(char::is_whitespace) {
+        words.push((offset, &original[offset..index]));
+        offset = index + separator.len();
+    }
+    words.push((offset, &original[offset..]));
+
+    let sentences = sentence::split(original);
+    let sentence_starts: HashSet<usize> = sentences.iter().map(|sentence| sentence.span.start).collect();
+    let sentence_ends: HashSet<usize> = sentences
+        .iter()
+        .filter(|sentence| sentence.terminator.is_some())
+        .map(|sentence| sentence.span.end)
+        .collect();
+
+    let mut tokens = Vec::new();
+    let mut index = 0;
+
+    while index < words.len() {
+        let (start, word) = words[index];
+
+        // consecutive whitespace produces empty words, only their whitespace is kept.
+        if word.is_empty() {
+            tokens.extend(whitespace(start));
+            index += 1;
+            continue;
+        }
 
-    for i in 0..words.len() {
-        let punctuation = punctuation_regex
-            .find(words[i])
-            .map(|punc| punc.as_str())
-            .unwrap_or("");
-        let cow = punctuation_regex.replace_all(words[i], "");
-        let mut word_no_punctuation = String::new();
-        word_no_punctuation.push_str(&cow);
+        let sentence_start = sentence_starts.contains(&start);
+        if options.fillers && sentence_start {
+            tokens.extend(start_sentence(start, translation, rng));
+        }
 
-        let translated_punctuation;
-        // edge case where input is e.g. "you & me".
-        // & gets replaced with "", crashing the translate_word function
-        // as "" can't be accessed by [0].
-        if word_no_punctuation.len() == 0 {
-            word_no_punctuation.push_str(words[i]);
-            translated_punctuation = String::from("");
-        } else {
-            translated_punctuation = translate_punctuation(&punctuation, &translation);
+        // the dots of abbreviations are part of the word, which is kept as it is.
+        if sentence::is_abbreviation(word) {
+            tokens.push(Token::new(start..start + word.len(), word, TokenKind::Word));
+            tokens.extend(whitespace(start + word.len()));
+            index += 1;
+            continue;
         }
 
+        // numbers keep their punctuation, except for the dot ending a sentence.
+        let word_end = start + word.len();
+        if let Some(length) = number::number_length(word, sentence_ends.contains(&word_end)) {
+            let (number, rest) = word.split_at(length);
+            let translated_number = translate_number(number, translation, options, sentence_start);
+            tokens.push(Token::new(start..start + length, &translated_number, TokenKind::Number));
+
+            let span = start + length..word_end;
+            if sentence_ends.contains(&word_end) && rest.chars().count() == 1 {
+                end_sentence(&mut tokens, span, rest, translation, options.fillers, rng);
+            } else if !rest.is_empty() {
+                tokens.push(Token::new(span, rest, TokenKind::Punctuation));
+            }
+            tokens.extend(whitespace(word_end));
+            index += 1;
+            continue;
+        }
 
-        #[cfg(feature = "interlude")]
-        let mut translated_word = translate_word(&word_no_punctuation, &translation);
-        #[cfg(not(feature = "interlude"))]
-        let translated_word = translate_word(&word_no_punctuation, &translation);
+        let (length, translated_word) = match translate_phrase(&words[index..], translation, rng) {
+            Some((length, phrase)) => (length, phrase),
+            None => {
+                let word_no_punctuation = punctuation_regex.replace_all(word, "");
+
+                // edge case where input is e.g. "you & me".
+                // & gets replaced with "", crashing the translate_word function
+                // as "" can't be accessed by [0].
+                if word_no_punctuation.is_empty() {
+                    (1, translate_normalized(word, translation, options, sentence_start, rng, hook))
+                } else {
+                    (1, translate_normalized(&word_no_punctuation, translation, options, sentence_start, rng, hook))
+                }
+            }
+        };
+
+        let (last_start, last_word) = words[index + length - 1];
+        let end = last_start + last_word.len();
+        let punctuation = trailing_punctuation_regex()
+            .find(last_word)
+            .filter(|_| !punctuation_regex.replace_all(last_word, "").is_empty());
+        let word_span = match punctuation {
+            Some(punc) => start..last_start + punc.start(),
+            None => start..end,
+        };
+        if length > 1 {
+            hook(Step::Translated(&original[word_span.clone()]));
+        }
+        tokens.push(Token::new(word_span.clone(), &translated_word, TokenKind::Word));
 
+        // a dictionary without an interlude never gets one.
         #[cfg(feature = "interlude")]
-        if is_one_percent_chance() {
-            translated_word = add_interlude(&translated_word, &translation);
+        if !translation.interlude.is_empty() && is_one_percent_chance(rng) {
+            // the interlude comes before the punctuation, so its span does as well.
+            tokens.push(Token::new(word_span.end..word_span.end, &translate_interlude(translation), TokenKind::Interlude));
+        }
+
+        match punctuation {
+            Some(punc) => {
+                let span = last_start + punc.start()..last_start + punc.end();
+                if sentence_ends.contains(&end) {
+                    end_sentence(&mut tokens, span, punc.as_str(), translation, options.fillers, rng);
+                } else {
+                    tokens.push(Token::new(span, punc.as_str(), TokenKind::Punctuation));
+                }
+            }
+            // interjections go between two words of a sentence, never next to punctuation.
+            None if options.fillers && index + length < words.len() => {
+                if let Some(interjection) = translation.interjections.pick(rng) {
+                    tokens.push(Token::new(end..end, &format!(" {}", interjection), TokenKind::Filler));
+                }
+            }
+            None => {}
+        }
+        tokens.extend(whitespace(end));
+
+        index += length;
+    }
+
+    let first_content = tokens.iter().position(|token| token.kind != TokenKind::Whitespace);
+    match first_content {
+        Some(first) => {
+            let last = tokens.iter().rposition(|token| token.kind != TokenKind::Whitespace).unwrap();
+            tokens.truncate(last + 1);
+            tokens.drain(..first);
+        }
+        None => tokens.clear(),
+    }
+
+    tokens
+}
+
+/// Translates the longest phrase of the dictionary `words` start with, returning how many
+/// words it spans.
+///
+/// Only the last word of a phrase may end with punctuation. Phrases are used as they are,
+/// except for the case of their first letter, which follows the original.
+fn translate_phrase(words: &[(usize, &str)], translation: &Dictionary, rng: &mut dyn RngCore) -> Option<(usize, String)> {
+    let punctuation_regex = punctuation_regex();
+    let longest = translation.longest_phrase.min(words.len());
+
+    for length in (2..=longest).rev() {
+        let (last, inner) = words[..length].split_last().unwrap();
+        if inner.iter().any(|(_, word)| word.is_empty() || punctuation_regex.is_match(word)) {
+            continue;
+        }
+        let last = match trailing_punctuation_regex().find(last.1) {
+            None => last.1,
+            Some(punc) => &last.1[..punc.start()],
+        };
+        if last.is_empty() || punctuation_regex.is_match(last) {
+            continue;
         }
 
-        meddl.push_str(&translated_word);
-        meddl.push_str(&translated_punctuation);
-        meddl.push_str(" ");
+        let mut phrase: Vec<&str> = inner.iter().map(|(_, word)| *word).collect();
+        phrase.push(last);
+        if let Some(translated) = lookup_phrase(&phrase.join(" "), translation, rng) {
+            return Some((length, translated));
+        }
+    }
+
+    None
+}
+
+fn lookup_phrase(phrase: &str, translation: &Dictionary, rng: &mut dyn RngCore) -> Option<String> {
+    let phrase = &*normalize::nfc(phrase);
+    let pick = |pool: &[Text], rng: &mut dyn RngCore| String::from(pool[get_random_index(pool, rng)].as_ref());
+
+    if let Some(pool) = translation.translation(phrase).filter(|pool| !pool.is_empty()) {
+        return Some(pick(pool, rng));
+    }
+
+    // "Guten Morgen" at the start of a sentence should also match "guten Morgen" and vice versa.
+    let first = phrase.chars().next().filter(|first| first.is_alphabetic())?;
+    let is_capitalized = first.is_uppercase();
+    let toggled = if is_capitalized { lowercase_first(phrase) } else { capitalize_word(phrase) };
+    let pool = translation.translation(&toggled).filter(|pool| !pool.is_empty())?;
+    let translated = pick(pool, rng);
+
+    if translated.is_empty() {
+        Some(translated)
+    } else if is_capitalized {
+        Some(capitalize_word(&translated))
+    } else {
+        Some(lowercase_first(&translated))
+    }
+}
+
+/// Translates `word` in normalisation form C, also looking up transliterations if asked to.
+fn translate_normalized(
+    word: &str,
+    translation: &Dictionary,
+    options: Options,
+    sentence_start: bool,
+    rng: &mut dyn RngCore,
+    hook: &mut dyn FnMut(Step),
+) -> String {
+    let word = normalize::nfc(word);
+    let is_known = translation.translation(&word).is_some() || translation.is_ignored(&word);
+
+    if options.transliteration && !is_known {
+        if let Some(original) = translation.transliterated(&word) {
+            // the translation is written without umlauts as well, like the original.
+            let translated = translate_word(original, translation, sentence_start, rng, &mut |step| hook(step.with_word(&word)));
+            return normalize::transliterate(&translated).into_owned();
+        }
     }
 
-    String::from(meddl.trim())
+    translate_word(&word, translation, sentence_start, rng, hook)
 }
 
-fn translate_word<'a>(word: &'a str, translation: &'a Value) -> String {
+fn translate_word(
+    word: &str,
+    translation: &Dictionary,
+    sentence_start: bool,
+    rng: &mut dyn RngCore,
+    hook: &mut dyn FnMut(Step),
+) -> String {
+    let original = word;
     let is_noun = word
         .chars()
         .collect::<Vec<char>>()[0]
         .is_uppercase();
 
-    let mut word = translate_quotation_marks(&word, translation);
+    let mut word = translate_quotation_marks(word, translation);
 
-    if is_ignored_word(&word, &translation) {
+    if translation.is_ignored(&word) {
+        hook(Step::Ignored(original));
         return word;
     }
 
-    if let Some(_key) = translation["translations"].get(&word) {
-        let possible_translations = translation["translations"][&word]
-            .as_array()
-            .unwrap();
-        let random = get_random_index(&possible_translations);
+    let is_translated = match translation.translation(&word) {
+        Some(possible_translations) if !possible_translations.is_empty() => {
+            let random = get_random_index(possible_translations, rng);
+            word = String::from(possible_translations[random].as_ref());
+            true
+        }
+        _ => {
+            word = twist_en(&word, translation);
+            false
+        }
+    };
 
-        let translated_word = possible_translations[random]
-            .as_str()
-            .unwrap_or(&word);
-        word = String::from(translated_word);
+    let context = Context { is_noun, sentence_start };
+    let twisted = twist_word(&word, translation, context, &mut |index| hook(Step::Rule(index)));
+    // "CSS" becomes "Css" like every noun, which alone doesn't make it meddl-ish.
+    hook(if is_translated {
+        Step::Translated(original)
+    } else if twisted.to_lowercase() != original.to_lowercase() {
+        Step::Twisted(original)
     } else {
-        word = twist_en(&word, &translation);
-        
-    }
+        Step::Untouched(original)
+    });
 
-    word = word.to_lowercase();
+    twisted
+}
+
+/// Lowercases `word` and applies the beginnings, twisted chars and rules, calling `fired` with
+/// the index of every rule that matched.
+fn twist_word(word: &str, translation: &Dictionary, context: Context, fired: &mut dyn FnMut(usize)) -> String {
+    let mut word = word.to_lowercase();
 
-    word = translate_beginning(&word, &translation);
-    word = twist_chars(&word, &translation);
+    word = translate_beginning(&word, translation);
+    word = twist_chars(&word, translation);
+    word = translation.rules.apply_with(&word, context, fired);
 
-    if is_noun {
+    if context.is_noun {
         return capitalize_word(&word);
     }
 
     word
 }
 
-fn twist_chars<'a>(word: &'a str, translation: &'a Value) -> String {
-    let twisted_chars = translation["twistedChars"]
-        .as_object()
-        .unwrap();
+/// Writes `number` in words if asked to and the dictionary has words for it.
+fn translate_number(number: &str, translation: &Dictionary, options: Options, sentence_start: bool) -> String {
+    match number::number_word(number, translation).filter(|_| options.number_words) {
+        Some(word) if sentence_start => capitalize_word(word),
+        Some(word) => String::from(word),
+        None => String::from(number),
+    }
+}
+
+fn twist_chars(word: &str, translation: &Dictionary) -> String {
     let mut word = String::from(word);
 
-    for pair in twisted_chars.iter() {
-        if word.contains(pair.0) {
-            word = word
-                .replace(pair.0, pair.1
-                    .as_str()
-                    .unwrap()
-                    .to_lowercase()
-                    .as_str(),
-                );
+    for (to_replace, replacement) in translation.twisted_chars.iter() {
+        if word.contains(to_replace.as_ref()) {
+            word = word.replace(to_replace.as_ref(), &replacement.to_lowercase());
         }
     }
 
-    String::from(word)
+    word
 }
 
-fn twist_en<'a>(word: &'a str, translation: &'a Value) -> String {
-    let mut twisted = String::from(word);
+/// Replaces the longest suffix of `word` that has an `en` rule applying to it.
+fn twist_en(word: &str, translation: &Dictionary) -> String {
+    let lowercase = word.to_lowercase();
+    let ending = translation
+        .en
+        .iter()
+        .filter(|ending| ending.applies_to(word, &lowercase))
+        .min_by_key(|ending| Reverse(ending.suffix.len()));
+
+    match ending {
+        Some(ending) => format!("{}{}", &word[..word.len() - ending.suffix.len()], ending.replacement),
+        None => String::from(word),
+    }
+}
 
-    let ens = translation["en"]
-        .as_object()
-        .unwrap();
+fn translate_punctuation(punctuation: &str, translation: &Dictionary, rng: &mut dyn RngCore) -> String {
+    let pool = match punctuation_pool(punctuation, translation) {
+        Some(pool) => pool,
+        None => return String::from(punctuation),
+    };
+    let random = get_random_index(pool, rng);
 
-    for array in ens.iter() {
-        let to_replace = array.0;
-        if word.ends_with(to_replace) {
-            let position = word.rfind(to_replace).unwrap();
+    String::from(pool[random].as_ref())
+}
 
-            twisted
-                .replace_range(position..word.len(), array.1
-                    .as_str()
-                    .unwrap()
-                )
-        }
+/// Hook for the start of a sentence at `start`, which may get an opener followed by a space.
+fn start_sentence(start: usize, translation: &Dictionary, rng: &mut dyn RngCore) -> Option<Token> {
+    let opener = translation.openers.pick(rng)?;
+
+    Some(Token::new(start..start, &format!("{} ", opener), TokenKind::Filler))
+}
+
+/// Hook for the punctuation ending a sentence, replacing it from the pools. With `fillers` a
+/// closer may be put right in front of it.
+fn end_sentence(
+    tokens: &mut Vec<Token>,
+    span: Range<usize>,
+    punctuation: &str,
+    translation: &Dictionary,
+    fillers: bool,
+    rng: &mut dyn RngCore,
+) {
+    let closer = if fillers { translation.closers.pick(rng) } else { None };
+    if let Some(closer) = closer {
+        tokens.push(Token::new(span.start..span.start, closer, TokenKind::Filler));
     }
 
-    twisted
+    // the pools may contain the punctuation itself, which is still a suffix.
+    let kind = if punctuation_pool(punctuation, translation).is_none() {
+        TokenKind::Punctuation
+    } else {
+        TokenKind::Suffix
+    };
+    tokens.push(Token::new(span, &translate_punctuation(punctuation, translation, rng), kind));
 }
 
-fn translate_punctuation<'a>(punctuation: &'a str, translation: &'a Value) -> String {
-    return match punctuation {
-        "." => {
-            let dot_pool = translation["dot"]
-                .as_array()
-                .unwrap();
-            let random = get_random_index(dot_pool);
-            let translated_dot = dot_pool[random]
-                .as_str()
-                .unwrap();
-
-            String::from(translated_dot)
-        }
-        "!" => {
-            let exclamation_mark_pool = translation["exclamationMark"]
-                .as_array()
-                .unwrap();
-            let random = get_random_index(exclamation_mark_pool);
-            let translated_exclamation_mark = exclamation_mark_pool[random]
-                .as_str()
-                .unwrap();
-
-            String::from(translated_exclamation_mark)
-        }
-        "?" => {
-            let question_mark_pool = translation["questionMark"]
-                .as_array()
-                .unwrap();
-            let random = get_random_index(question_mark_pool);
-            let translated_question_mark = question_mark_pool[random]
-                .as_str()
-                .unwrap();
-
-            String::from(translated_question_mark)
-        }
-        _ => String::from(punctuation)
+/// The pool `punctuation` is replaced from, `None` if it's kept as it is.
+fn punctuation_pool<'a>(punctuation: &str, translation: &'a Dictionary) -> Option<&'a [Text]> {
+    let pool = match punctuation {
+        "." => &translation.dot,
+        "!" => &translation.exclamation_mark,
+        "?" => &translation.question_mark,
+        _ => return None,
     };
+
+    (!pool.is_empty()).then(|| pool.as_ref())
 }
 
-fn translate_quotation_marks(word: &str, translation: &Value) -> String {
-    if word.starts_with("\"") {
-        return word.replacen("\"", translation["quotationMark"]
-            .as_str()
-            .unwrap(),
-        1);
+fn translate_quotation_marks(word: &str, translation: &Dictionary) -> String {
+    if word.starts_with('"') {
+        return word.replacen('"', &translation.quotation_mark, 1);
     }
     String::from(word)
 }
 
-fn translate_beginning(word: &str, translation: &Value) -> String {
-    let beginnings = translation["twistBeginning"].as_object().unwrap();
-
-    for beginning_object in beginnings.iter() {
-        if word.starts_with(beginning_object.0) {
-            return word.replacen(beginning_object.0, beginning_object.1.as_str().unwrap(), 1)
+fn translate_beginning(word: &str, translation: &Dictionary) -> String {
+    for (beginning, replacement) in translation.twist_beginning.iter() {
+        if word.starts_with(beginning.as_ref()) {
+            return word.replacen(beginning.as_ref(), replacement, 1)
         }
     }
 
@@ -256,18 +691,8 @@ fn translate_beginning(word: &str, translation: &Value) -> String {
 }
 
 #[cfg(feature = "interlude")]
-fn add_interlude(word_to_add_to: &str, translation: &Value) -> String {
-    let interlude = translation["interlude"]
-        .as_str()
-        .unwrap();
-
-        let word_with_interlude = format!(
-            "{}{}",
-            word_to_add_to,
-            interlude
-        );
-
-        word_with_interlude
+fn translate_interlude(translation: &Dictionary) -> String {
+    String::from(translation.interlude.as_ref())
 }
 
 #[cfg(test)]
@@ -284,107 +709,352 @@ mod tests {
         fn should_return_empty_string_on_empty_string_on_input() {
             assert_eq!(translate(""), "");
         }
+
+        #[test]
+        fn should_keep_words_starting_with_symbols() {
+            for (original, symbol) in [
+                ("„Hallo Welt“, sagte er.", "„"),
+                ("Das kostet 5 € pro Stück", "€"),
+                ("Hallo – ein Test", "–"),
+                ("Hallo 😀 Welt", "😀"),
+            ] {
+                assert!(translate(original).contains(symbol), "{} lost {}", original, symbol);
+            }
+        }
+
+        #[test]
+        fn should_keep_numbers_with_their_punctuation() {
+            let translator = crate::Translator::new().with_seed(1);
+
+            assert_eq!(
+                translator.translate("3,5 und 1.000 um 3.10.2024 um 12:30, um 3. Mai"),
+                "3,5 und 1.000 um 3.10.2024 um 12:30, um 3. Mai"
+            );
+        }
+
+        #[test]
+        fn should_normalize_decomposed_umlauts() {
+            let dictionary = crate::Dictionary::from_json(r#"{"translations": {"hübsch": ["schee"]}}"#).unwrap();
+
+            assert_eq!(crate::Translator::new().with_dictionary(dictionary).translate("hu\u{308}bsch"), "schee");
+        }
+
+        #[test]
+        fn should_find_transliterated_words_if_asked_to() {
+            let dictionary = crate::Dictionary::from_json(r#"{"translations": {"schön": ["schee"], "hübsch": ["hübsch"]}}"#).unwrap();
+            let translator = crate::Translator::new().with_dictionary(dictionary);
+
+            assert_eq!(translator.translate("schoen huebsch"), "schoen huebsch");
+            assert_eq!(translator.clone().with_transliteration().translate("schoen huebsch"), "schee huebsch");
+            assert_eq!(translator.with_transliteration().translate("schön hübsch"), "schee hübsch");
+        }
+
+        #[test]
+        fn should_write_numbers_in_words_if_asked_to() {
+            let translator = crate::Translator::new().with_number_words().with_seed(1);
+
+            assert_eq!(translator.translate("2 und 13 und 2,5"), "Zwaa und 13 und 2,5");
+        }
+    }
+
+    mod translate_tokens {
+        use crate::{translate_tokens, Dictionary, Token, TokenKind, Translator};
+
+        fn join(tokens: Vec<Token>) -> String {
+            tokens.into_iter().map(|token| token.text).collect()
+        }
+
+        /// Seeded, so the `interlude` feature can't add tokens at random.
+        fn seeded() -> Translator {
+            Translator::new().with_seed(1)
+        }
+
+        #[test]
+        fn should_map_tokens_to_original_spans() {
+            assert_eq!(seeded().translate_tokens("Rust, programmiert"), vec![
+                Token::new(0..4, "Rusd", TokenKind::Word),
+                Token::new(4..5, ",", TokenKind::Punctuation),
+                Token::new(5..6, " ", TokenKind::Whitespace),
+                Token::new(6..18, "brogrammierd", TokenKind::Word),
+            ]);
+        }
+
+        #[test]
+        fn should_mark_replaced_punctuation_as_suffix() {
+            let tokens = seeded().translate_tokens("Rust.");
+
+            assert_eq!(tokens[1].span, 4..5);
+            assert_eq!(tokens[1].kind, TokenKind::Suffix);
+        }
+
+        #[test]
+        fn should_mark_punctuation_drawn_unchanged_from_its_pool_as_suffix() {
+            let dictionary = Dictionary::from_json(r#"{"dot": ["."], "exclamationMark": ["!"]}"#).unwrap();
+            let tokens = Translator::new().with_dictionary(dictionary).translate_tokens("Rust! Rust.");
+
+            assert_eq!(tokens[1], Token::new(4..5, "!", TokenKind::Suffix));
+            assert_eq!(tokens[4], Token::new(10..11, ".", TokenKind::Suffix));
+        }
+
+        #[cfg(feature = "interlude")]
+        #[test]
+        fn should_put_interludes_between_the_word_and_its_punctuation() {
+            let dictionary = Dictionary::from_json(r#"{"interlude": "ähm", "dot": ["."]}"#).unwrap();
+            let translator = Translator::new().with_dictionary(dictionary).with_seed(7);
+            let tokens = translator.translate_tokens(&"Rust. ".repeat(500));
+            let interlude = tokens.iter().position(|token| token.kind == TokenKind::Interlude).unwrap();
+
+            assert_eq!(tokens[interlude].span.start, tokens[interlude - 1].span.end);
+            assert_eq!(tokens[interlude + 1].span.start, tokens[interlude].span.end);
+            assert!(tokens.windows(2).all(|pair| pair[0].span.end <= pair[1].span.start));
+        }
+
+        #[cfg(feature = "interlude")]
+        #[test]
+        fn should_not_insert_interludes_without_one_in_the_dictionary() {
+            let translator = Translator::new().with_dictionary(Dictionary::from_json("{}").unwrap()).with_seed(7);
+            let tokens = translator.translate_tokens(&"Rust ".repeat(500));
+
+            assert!(tokens.iter().all(|token| token.kind != TokenKind::Interlude));
+        }
+
+        #[test]
+        fn should_keep_runs_of_punctuation_together() {
+            assert_eq!(seeded().translate_tokens("Ende..."), vec![
+                Token::new(0..4, "Ende", TokenKind::Word),
+                Token::new(4..7, "...", TokenKind::Punctuation),
+            ]);
+            assert_eq!(seeded().translate_tokens("Was?!"), vec![
+                Token::new(0..3, "Was", TokenKind::Word),
+                Token::new(3..5, "?!", TokenKind::Punctuation),
+            ]);
+        }
+
+        #[test]
+        fn should_split_words_at_any_whitespace() {
+            let tokens = seeded().translate_tokens("Rust.\nRust\tRust");
+
+            assert_eq!(tokens[0], Token::new(0..4, "Rusd", TokenKind::Word));
+            assert_eq!(tokens[1].span, 4..5);
+            assert_eq!(tokens[2], Token::new(5..6, "\n", TokenKind::Whitespace));
+            assert_eq!(tokens[3], Token::new(6..10, "Rusd", TokenKind::Word));
+            assert_eq!(tokens[4], Token::new(10..11, "\t", TokenKind::Whitespace));
+            assert_eq!(tokens[5], Token::new(11..15, "Rusd", TokenKind::Word));
+        }
+
+        #[test]
+        fn should_only_replace_punctuation_ending_a_sentence() {
+            let tokens = seeded().translate_tokens("Rust z.B. am 3. Mai.");
+
+            assert_eq!(tokens[2], Token::new(5..9, "z.B.", TokenKind::Word));
+            assert_eq!(tokens[6], Token::new(13..15, "3.", TokenKind::Number));
+            assert_eq!(tokens.last().unwrap().kind, TokenKind::Suffix);
+        }
+
+        #[test]
+        fn should_insert_fillers_at_sentence_boundaries() {
+            let dictionary = Dictionary::from_json(
+                r#"{
+                    "openers": {"probability": 1, "phrases": ["Meddl!"]},
+                    "interjections": {"probability": 1, "phrases": ["etzala"]},
+                    "closers": {"probability": 1, "phrases": [", gell"]},
+                    "dot": ["."]
+                }"#,
+            )
+            .unwrap();
+            let translator = Translator::new().with_dictionary(dictionary).with_fillers();
+            let tokens = translator.translate_tokens("ich bin, du bist.");
+
+            assert_eq!(tokens[0], Token::new(0..0, "Meddl! ", TokenKind::Filler));
+            assert_eq!(tokens[2], Token::new(3..3, " etzala", TokenKind::Filler));
+            assert_eq!(tokens[tokens.len() - 2], Token::new(16..16, ", gell", TokenKind::Filler));
+            assert_eq!(join(tokens), "Meddl! ich etzala bin, du etzala bist, gell.");
+        }
+
+        #[test]
+        fn should_keep_inner_whitespace_only() {
+            assert_eq!(seeded().translate_tokens(" Rust  Rust "), vec![
+                Token::new(1..5, "Rusd", TokenKind::Word),
+                Token::new(5..6, " ", TokenKind::Whitespace),
+                Token::new(6..7, " ", TokenKind::Whitespace),
+                Token::new(7..11, "Rusd", TokenKind::Word),
+            ]);
+        }
+
+        #[test]
+        fn should_return_nothing_on_empty_input() {
+            assert!(translate_tokens("").is_empty());
+        }
+    }
+
+    mod translate_phrase {
+        use crate::{Dictionary, Translator};
+
+        fn translator() -> Translator {
+            let dictionary = Dictionary::from_json(
+                r#"{"translations": {"ich weiß": ["i waas"], "ich weiß nicht": ["waas ned"], "Guten Morgen": ["Moin"]}}"#,
+            )
+            .unwrap();
+
+            Translator::new().with_dictionary(dictionary)
+        }
+
+        #[test]
+        fn should_prefer_the_longest_phrase() {
+            assert_eq!(translator().translate("ich weiß nicht."), "waas ned.");
+            assert_eq!(translator().translate("ich weiß"), "i waas");
+        }
+
+        #[test]
+        fn should_follow_the_original_capitalisation() {
+            assert_eq!(translator().translate("Ich weiß nicht"), "Waas ned");
+            assert_eq!(translator().translate("also guten Morgen"), "also moin");
+        }
+
+        #[test]
+        fn should_not_match_across_punctuation() {
+            assert_eq!(translator().translate("ich, weiß nicht"), "ich, weiß nicht");
+        }
+
+        #[test]
+        fn should_span_all_words_of_a_phrase() {
+            let tokens = translator().translate_tokens("Guten Morgen!");
+
+            assert_eq!(tokens[0].span, 0..12);
+            assert_eq!(tokens[1].span, 12..13);
+        }
     }
 
     mod translate_word {
+        use rand::thread_rng;
         use super::super::*;
         #[test]
         fn should_ignore_word() {
-            let translation = serde_json::from_str("{\"ignored\": [\"whatever\"], \"translations\": { \"whatever\": [\"something\"]}}").unwrap();
+            let translation = Dictionary::from_json("{\"ignored\": [\"whatever\"], \"translations\": { \"whatever\": [\"something\"]}}").unwrap();
 
-            assert_eq!(translate_word("whatever", &translation), "whatever");
+            assert_eq!(translate_word("whatever", &translation, false, &mut thread_rng(), &mut |_| {}), "whatever");
         }
 
         #[test]
         fn should_translate_word() {
-            let translation = serde_json::from_str("{\"translations\": { \"Whatever\": [\"Something\"]}, \"ignored\": [], \"en\": {}, \"twistedChars\": {}, \"twistBeginning\": {}}").unwrap();
+            let translation = Dictionary::from_json("{\"translations\": { \"Whatever\": [\"Something\"]}, \"ignored\": [], \"en\": {}, \"twistedChars\": {}, \"twistBeginning\": {}}").unwrap();
 
-            assert_eq!(translate_word("Whatever", &translation), "Something");
+            assert_eq!(translate_word("Whatever", &translation, false, &mut thread_rng(), &mut |_| {}), "Something");
         }
 
         #[test]
         fn should_translate_nn_correctly() {
-            let translation = serde_json::from_str("{\"translations\": { \"wenn\": [\"wen\"]}, \"ignored\": [], \"en\": {}, \"twistedChars\": {}, \"twistBeginning\": {}}").unwrap();
+            let translation = Dictionary::from_json("{\"translations\": { \"wenn\": [\"wen\"]}, \"ignored\": [], \"en\": {}, \"twistedChars\": {}, \"twistBeginning\": {}}").unwrap();
+
+            assert_eq!(translate_word("wenn", &translation, false, &mut thread_rng(), &mut |_| {}), "wen");
+        }
+
+        #[test]
+        fn should_apply_rules_after_twisting() {
+            let translation = Dictionary::from_json("{\"twistedChars\": {\"t\": \"d\"}, \"rules\": [{\"pattern\": \"^ich$\", \"replacement\": \"i\", \"conditions\": [\"sentenceStart\"]}, {\"pattern\": \"d$\", \"replacement\": \"dd\"}]}").unwrap();
 
-            assert_eq!(translate_word("wenn", &translation), "wen");
+            assert_eq!(translate_word("Ich", &translation, true, &mut thread_rng(), &mut |_| {}), "I");
+            assert_eq!(translate_word("ich", &translation, false, &mut thread_rng(), &mut |_| {}), "ich");
+            assert_eq!(translate_word("Rat", &translation, false, &mut thread_rng(), &mut |_| {}), "Radd");
         }
     }
 
     mod twist_chars {
-        use serde_json::Value;
-        use crate::twist_chars;
+        use crate::{twist_chars, Dictionary};
 
         #[test]
         fn should_twist_chars() {
-            let translation = serde_json::from_str("{\"twistedChars\": {\"ck\": \"gg\"}}").unwrap();
+            let translation = Dictionary::from_json("{\"twistedChars\": {\"ck\": \"gg\"}}").unwrap();
 
             assert_eq!(twist_chars("wicked", &translation), "wigged");
         }
 
         #[test]
         fn should_twist_multiple_chars() {
-            let translation: Value = serde_json::from_str("{\"twistedChars\": {\"z\": \"ds\", \"p\": \"b\"}}").unwrap();
+            let translation = Dictionary::from_json("{\"twistedChars\": {\"z\": \"ds\", \"p\": \"b\"}}").unwrap();
 
             assert_eq!(twist_chars("pommespanzer", &translation), "bommesbandser");
         }
     }
 
     mod twist_en {
-        use crate::twist_en;
+        use crate::{twist_en, Dictionary};
 
         #[test]
         fn should_twist_en_end_of_word() {
-            let translation = serde_json::from_str("{\"en\": {\"en!\": \"ne!\"}, \"ignored\": []}").unwrap();
+            let translation = Dictionary::from_json("{\"en\": {\"en!\": \"ne!\"}, \"ignored\": []}").unwrap();
 
             assert_eq!(twist_en("laufen!", &translation), "laufne!");
         }
 
         #[test]
         fn should_twist_en_ignore_char_within() {
-            let translation = serde_json::from_str("{\"en\": {\"en\": \"ne\"}, \"ignored\": []}").unwrap();
+            let translation = Dictionary::from_json("{\"en\": {\"en\": \"ne\"}, \"ignored\": []}").unwrap();
 
             assert_eq!(twist_en("denken", &translation), "denkne");
         }
+
+        #[test]
+        fn should_only_apply_the_longest_suffix() {
+            let translation = Dictionary::from_json(r#"{"en": {"en": "ne", "ien": "ien", "nen": "nene"}}"#).unwrap();
+
+            assert_eq!(twist_en("Wien", &translation), "Wien");
+            assert_eq!(twist_en("können", &translation), "könnene");
+            assert_eq!(twist_en("laufen", &translation), "laufne");
+        }
+
+        #[test]
+        fn should_respect_stem_length_and_exceptions() {
+            let translation = Dictionary::from_json(
+                r#"{"version": 2, "en": [{"suffix": "en", "replacement": "ne", "minStem": 2, "except": ["Garten"]}, ["n", "m"]]}"#,
+            )
+            .unwrap();
+
+            assert_eq!(twist_en("den", &translation), "dem");
+            assert_eq!(twist_en("garten", &translation), "gartem");
+            assert_eq!(twist_en("denken", &translation), "denkne");
+        }
     }
 
     mod translate_punctuation {
-        use crate::translate_punctuation;
+        use rand::thread_rng;
+        use crate::{translate_punctuation, Dictionary};
 
         #[test]
         fn should_translate_punctuation_dot() {
-            let translation = serde_json::from_str("{\"dot\": [\" dot suffix.\"]}").unwrap();
+            let translation = Dictionary::from_json("{\"dot\": [\" dot suffix.\"]}").unwrap();
 
-            assert_eq!(translate_punctuation(".", &translation), " dot suffix.");
+            assert_eq!(translate_punctuation(".", &translation, &mut thread_rng()), " dot suffix.");
         }
 
         #[test]
         fn should_translation_punctuation_exclamation_mark() {
-            let translation = serde_json::from_str("{\"exclamationMark\": [\" exclamation mark suffix!\"]}").unwrap();
+            let translation = Dictionary::from_json("{\"exclamationMark\": [\" exclamation mark suffix!\"]}").unwrap();
 
-            assert_eq!(translate_punctuation("!", &translation), " exclamation mark suffix!");
+            assert_eq!(translate_punctuation("!", &translation, &mut thread_rng()), " exclamation mark suffix!");
         }
 
         #[test]
         fn should_translate_punctuation_question_mark() {
-            let translation = serde_json::from_str("{\"questionMark\": [\" question mark suffix?\"]}").unwrap();
+            let translation = Dictionary::from_json("{\"questionMark\": [\" question mark suffix?\"]}").unwrap();
 
-            assert_eq!(translate_punctuation("?", &translation), " question mark suffix?");
+            assert_eq!(translate_punctuation("?", &translation, &mut thread_rng()), " question mark suffix?");
         }
 
         #[test]
         fn should_translate_punctuation_return_anything_else() {
-            let translation = serde_json::from_str("{}").unwrap();
+            let translation = Dictionary::from_json("{}").unwrap();
 
-            assert_eq!(translate_punctuation("~", &translation), "~");
+            assert_eq!(translate_punctuation("~", &translation, &mut thread_rng()), "~");
         }
     }
 
     mod translate_quotation_marks {
-        use crate::translate_quotation_marks;
+        use crate::{translate_quotation_marks, Dictionary};
 
         #[test]
         fn should_translate_quotation_marks() {
-            let translation = serde_json::from_str("{\"quotationMark\":\"I cite: \\\"\"}").unwrap();
+            let translation = Dictionary::from_json("{\"quotationMark\":\"I cite: \\\"\"}").unwrap();
 
             assert_eq!(translate_quotation_marks("\"word\"", &translation), "I cite: \"word\"");
         }
@@ -392,25 +1062,25 @@ mod tests {
     }
 
     mod translate_beginning {
-        use crate::translate_beginning;
+        use crate::{translate_beginning, Dictionary};
 
         #[test]
         fn should_translate_st() {
-            let translation = serde_json::from_str("{\"twistBeginning\": {\"st\": \"schd\"}}").unwrap();
+            let translation = Dictionary::from_json("{\"twistBeginning\": {\"st\": \"schd\"}}").unwrap();
 
             assert_eq!(translate_beginning("stein", &translation), "schdein");
         }
 
         #[test]
         fn should_translate_sp() {
-            let translation = serde_json::from_str("{\"twistBeginning\": {\"sp\": \"schb\"}}").unwrap();
+            let translation = Dictionary::from_json("{\"twistBeginning\": {\"sp\": \"schb\"}}").unwrap();
 
             assert_eq!(translate_beginning("spinne", &translation), "schbinne");
         }
 
         #[test]
         fn should_ignore_anything_else() {
-            let translation = serde_json::from_str("{\"twistBeginning\": {\"sp\": \"schb\"}}").unwrap();
+            let translation = Dictionary::from_json("{\"twistBeginning\": {\"sp\": \"schb\"}}").unwrap();
 
             assert_eq!(translate_beginning("hallo", &translation), "hallo");
         }
@@ -424,5 +1094,12 @@ mod tests {
             assert_eq!(translate("."), ".");
             assert_eq!(translate("you & me"), "you & me");
         }
+
+        #[test]
+        fn should_keep_consecutive_spaces() {
+            let translator = crate::Translator::new().with_seed(1);
+
+            assert_eq!(translator.translate("Rust  programmiert"), "Rusd  brogrammierd");
+        }
     }
 }
\ No newline at end of file
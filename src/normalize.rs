@@ -0,0 +1,59 @@
+use std::borrow::Cow;
+
+use unicode_normalization::{is_nfc_quick, IsNormalized, UnicodeNormalization};
+
+/// `word` in Unicode normalisation form C, so decomposed umlauts match the dictionary.
+pub(crate) fn nfc(word: &str) -> Cow<'_, str> {
+    match is_nfc_quick(word.chars()) {
+        IsNormalized::Yes => Cow::Borrowed(word),
+        _ => Cow::Owned(word.nfc().collect()),
+    }
+}
+
+/// `word` with umlauts and ß written the way they are without a German keyboard, e.g.
+/// "größer" becomes "groesser".
+pub(crate) fn transliterate(word: &str) -> Cow<'_, str> {
+    if !word.contains(['ä', 'ö', 'ü', 'ß', 'Ä', 'Ö', 'Ü', 'ẞ']) {
+        return Cow::Borrowed(word);
+    }
+
+    let mut transliterated = String::with_capacity(word.len() + 4);
+    for c in word.chars() {
+        match c {
+            'ä' => transliterated.push_str("ae"),
+            'ö' => transliterated.push_str("oe"),
+            'ü' => transliterated.push_str("ue"),
+            'ß' => transliterated.push_str("ss"),
+            'Ä' => transliterated.push_str("Ae"),
+            'Ö' => transliterated.push_str("Oe"),
+            'Ü' => transliterated.push_str("Ue"),
+            'ẞ' => transliterated.push_str("SS"),
+            c => transliterated.push(c),
+        }
+    }
+
+    Cow::Owned(transliterated)
+}
+
+#[cfg(test)]
+mod tests {
+    mod nfc {
+        use crate::normalize::nfc;
+
+        #[test]
+        fn should_compose_umlauts() {
+            assert_eq!(nfc("gro\u{308}ßen"), "größen");
+            assert_eq!(nfc("großen"), "großen");
+        }
+    }
+
+    mod transliterate {
+        use crate::normalize::transliterate;
+
+        #[test]
+        fn should_replace_umlauts_and_sharp_s() {
+            assert_eq!(transliterate("Größe Übel"), "Groesse Uebel");
+            assert_eq!(transliterate("Rust"), "Rust");
+        }
+    }
+}
@@ -0,0 +1,85 @@
+use std::sync::OnceLock;
+
+use regex::Regex;
+
+use crate::Dictionary;
+
+fn number_regex() -> &'static Regex {
+    static NUMBER: OnceLock<Regex> = OnceLock::new();
+
+    // integers, decimals ("3,5"), thousands ("1.000"), times ("12:30") and dates ("3.10.2024").
+    NUMBER.get_or_init(|| Regex::new(r"^[+-]?\d+(?:[.,:]\d+)*").expect("Could not compile number regex."))
+}
+
+/// Length of the number `word` starts with, `None` if `word` isn't a number.
+///
+/// A dot right after the number belongs to it (`3.`, `3.10.`), unless it is the dot ending
+/// the sentence. Anything following the number has to be punctuation.
+pub(crate) fn number_length(word: &str, ends_sentence: bool) -> Option<usize> {
+    let mut length = number_regex().find(word)?.end();
+    let rest = &word[length..];
+
+    if rest.starts_with('.') && !(ends_sentence && rest == ".") {
+        length += 1;
+    }
+    if word[length..].chars().any(char::is_alphanumeric) {
+        return None;
+    }
+
+    Some(length)
+}
+
+/// The words for `number` from the `numbers` section, `None` if there is no word for it.
+///
+/// Only whole numbers are written in words, decimals, times and dates are kept as they are.
+pub(crate) fn number_word<'a>(number: &str, translation: &'a Dictionary) -> Option<&'a str> {
+    if number.len() > 1 && number.starts_with('0') {
+        return None;
+    }
+    let value: usize = number.parse().ok()?;
+
+    translation.numbers.get(value).map(|word| word.as_ref())
+}
+
+#[cfg(test)]
+mod tests {
+    mod number_length {
+        use crate::number::number_length;
+
+        #[test]
+        fn should_match_decimals_times_and_dates() {
+            assert_eq!(number_length("3,5", false), Some(3));
+            assert_eq!(number_length("12:30,", false), Some(5));
+            assert_eq!(number_length("3.10.2024", false), Some(9));
+            assert_eq!(number_length("-1.000", false), Some(6));
+        }
+
+        #[test]
+        fn should_keep_the_dot_ending_a_sentence() {
+            assert_eq!(number_length("3.", false), Some(2));
+            assert_eq!(number_length("3.", true), Some(1));
+            assert_eq!(number_length("3.10.", true), Some(4));
+        }
+
+        #[test]
+        fn should_reject_words() {
+            assert_eq!(number_length("3er", false), None);
+            assert_eq!(number_length("Rust", false), None);
+        }
+    }
+
+    mod number_word {
+        use crate::number::number_word;
+        use crate::Dictionary;
+
+        #[test]
+        fn should_only_write_whole_numbers_in_words() {
+            let dictionary = Dictionary::from_json(r#"{"numbers": ["null", "aans", "zwaa"]}"#).unwrap();
+
+            assert_eq!(number_word("2", &dictionary), Some("zwaa"));
+            assert_eq!(number_word("3", &dictionary), None);
+            assert_eq!(number_word("02", &dictionary), None);
+            assert_eq!(number_word("1,5", &dictionary), None);
+        }
+    }
+}
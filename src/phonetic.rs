@@ -0,0 +1,332 @@
+//! Phonetic transcription of translated text, available with the `phonetic` feature.
+//!
+//! Words are transcribed with simple German grapheme-to-phoneme rules, unless the
+//! `pronunciations` section of the dictionary has an IPA transcription for the lowercase word.
+//! Everything that isn't a letter, like punctuation and whitespace, is kept as it is.
+//!
+//! # Example
+//!
+//! ```
+//! use meddl_translate::phonetic::{transcribe, Alphabet};
+//!
+//! assert_eq!(transcribe("Schbass machd", Alphabet::Ipa), "ʃbas maxt");
+//! assert_eq!(transcribe("Schbass machd", Alphabet::Sampa), "Sbas maxt");
+//! ```
+
+use crate::Dictionary;
+
+/// The alphabet of a transcription.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Alphabet {
+    /// The International Phonetic Alphabet.
+    Ipa,
+    /// The Speech Assessment Methods Phonetic Alphabet, IPA written in ASCII.
+    Sampa,
+}
+
+/// IPA symbols and their SAMPA equivalent, longer symbols first.
+const SAMPA: [(&str, &str); 25] = [
+    ("aɪ̯", "aI"),
+    ("aʊ̯", "aU"),
+    ("ɔʏ̯", "OY"),
+    ("ɔɪ̯", "OI"),
+    ("ɐ̯", "6"),
+    ("l̩", "l="),
+    ("n̩", "n="),
+    ("tʃ", "tS"),
+    ("ʃ", "S"),
+    ("ʒ", "Z"),
+    ("ç", "C"),
+    ("ŋ", "N"),
+    ("ʁ", "R"),
+    ("ɐ", "6"),
+    ("ə", "@"),
+    ("ɛ", "E"),
+    ("ɪ", "I"),
+    ("ɔ", "O"),
+    ("ʊ", "U"),
+    ("œ", "9"),
+    ("ʏ", "Y"),
+    ("ø", "2"),
+    ("ɡ", "g"),
+    ("ː", ":"),
+    ("ˈ", "\""),
+];
+
+/// Transcribes `text` using the pronunciations of the built-in dictionary.
+pub fn transcribe(text: &str, alphabet: Alphabet) -> String {
+    transcribe_with(text, Dictionary::builtin(), alphabet)
+}
+
+/// Transcribes `text` using the pronunciations of `dictionary`.
+///
+/// # Example
+///
+/// ```
+/// use meddl_translate::phonetic::{transcribe_with, Alphabet};
+/// use meddl_translate::Dictionary;
+///
+/// let dictionary = Dictionary::from_json(r#"{"pronunciations": {"servus": "sɛɐ̯vʊs"}}"#).unwrap();
+///
+/// assert_eq!(transcribe_with("Servus!", &dictionary, Alphabet::Sampa), "sE6vUs!");
+/// ```
+pub fn transcribe_with(text: &str, dictionary: &Dictionary, alphabet: Alphabet) -> String {
+    let mut transcription = String::with_capacity(text.len() * 2);
+    let mut word_start = None;
+    let push_word = |transcription: &mut String, word: &str| {
+        let ipa = transcribe_word(&word.to_lowercase(), dictionary);
+        match alphabet {
+            Alphabet::Ipa => transcription.push_str(&ipa),
+            Alphabet::Sampa => transcription.push_str(&to_sampa(&ipa)),
+        }
+    };
+
+    for (index, c) in text.char_indices() {
+        match (c.is_alphabetic(), word_start) {
+            (true, None) => word_start = Some(index),
+            (true, Some(_)) => {}
+            (false, start) => {
+                if let Some(start) = start {
+                    push_word(&mut transcription, &text[start..index]);
+                    word_start = None;
+                }
+                transcription.push(c);
+            }
+        }
+    }
+    if let Some(start) = word_start {
+        push_word(&mut transcription, &text[start..]);
+    }
+
+    transcription
+}
+
+/// The IPA transcription of a lowercase word.
+fn transcribe_word(word: &str, dictionary: &Dictionary) -> String {
+    if let Some(pronunciation) = dictionary.pronunciations.get(word) {
+        return String::from(pronunciation.as_ref());
+    }
+
+    let chars: Vec<char> = word.chars().collect();
+    let mut ipa = String::new();
+    let mut index = 0;
+
+    while index < chars.len() {
+        let (length, phonemes) = grapheme(&chars, index);
+        ipa.push_str(&phonemes);
+        index += length;
+    }
+
+    ipa
+}
+
+/// The phonemes of the grapheme starting at `index` and how many characters it spans.
+fn grapheme(chars: &[char], index: usize) -> (usize, String) {
+    let rest: String = chars[index..].iter().collect();
+    let at = |offset: usize| chars.get(index + offset).copied();
+    let previous = index.checked_sub(1).map(|previous| chars[previous]);
+    let remaining = chars.len() - index;
+    let is_word_end = |length: usize| remaining == length;
+
+    // "sp" and "st" at the beginning of a word, the "p" or "t" is read on its own.
+    if index == 0 && (rest.starts_with("sp") || rest.starts_with("st")) {
+        return (1, String::from("ʃ"));
+    }
+    if let Some((length, phonemes)) = multigraph(&rest, previous, chars.len() > 2 && index > 0, is_word_end) {
+        return (length, String::from(phonemes));
+    }
+
+    let c = chars[index];
+    // doubled consonants are pronounced once, "ss" is never voiced.
+    if c == 's' && at(1) == Some('s') {
+        return (2, String::from("s"));
+    }
+    if !is_vowel(c) && at(1) == Some(c) {
+        return (2, single(c, is_word_end(2), previous, at(2)));
+    }
+    // a silent "h" makes the vowel in front of it long.
+    if is_vowel(c) && at(1) == Some('h') {
+        return (2, format!("{}ː", long_vowel(c)));
+    }
+
+    (1, single(c, is_word_end(1), previous, at(1)))
+}
+
+/// Graphemes of more than one character.
+fn multigraph(rest: &str, previous: Option<char>, is_ending: bool, is_word_end: impl Fn(usize) -> bool) -> Option<(usize, &'static str)> {
+    const MULTIGRAPHS: [(&str, &str); 21] = [
+        ("tsch", "tʃ"),
+        ("sch", "ʃ"),
+        ("chs", "ks"),
+        ("ck", "k"),
+        ("ng", "ŋ"),
+        ("nk", "ŋk"),
+        ("qu", "kv"),
+        ("ph", "f"),
+        ("th", "t"),
+        ("tz", "ts"),
+        ("dt", "t"),
+        ("ei", "aɪ̯"),
+        ("ai", "aɪ̯"),
+        ("ey", "aɪ̯"),
+        ("eu", "ɔʏ̯"),
+        ("äu", "ɔʏ̯"),
+        ("au", "aʊ̯"),
+        ("ie", "iː"),
+        ("aa", "aː"),
+        ("ee", "eː"),
+        ("oo", "oː"),
+    ];
+
+    if is_ending && is_word_end(2) {
+        match rest {
+            "ig" => return Some((2, "ɪç")),
+            "er" => return Some((2, "ɐ")),
+            "en" => return Some((2, "ən")),
+            "el" => return Some((2, "əl")),
+            _ => {}
+        }
+    }
+    if is_ending && rest == "e" {
+        return Some((1, "ə"));
+    }
+
+    if let Some((grapheme, phonemes)) = MULTIGRAPHS.iter().find(|(grapheme, _)| rest.starts_with(grapheme)) {
+        return Some((grapheme.chars().count(), phonemes));
+    }
+
+    // "ch" is a back sound after "a", "o", "u" and "au", but not after "eu" or "äu".
+    match previous {
+        _ if !rest.starts_with("ch") => None,
+        Some('a') | Some('o') | Some('u') => Some((2, "x")),
+        _ => Some((2, "ç")),
+    }
+}
+
+/// The phonemes of a single character.
+fn single(c: char, is_word_end: bool, previous: Option<char>, next: Option<char>) -> String {
+    let phonemes = match c {
+        'a' => "a",
+        'e' => "ɛ",
+        'i' => "ɪ",
+        'o' => "ɔ",
+        'u' => "ʊ",
+        'ä' => "ɛ",
+        'ö' => "œ",
+        'ü' | 'y' => "ʏ",
+        // final devoicing.
+        'b' if is_word_end => "p",
+        'd' if is_word_end => "t",
+        'g' if is_word_end => "k",
+        'g' => "ɡ",
+        // a voiced "s" in front of a vowel, unless it follows a consonant.
+        's' if next.is_some_and(is_vowel) && previous.is_none_or(is_vowel) => "z",
+        'ß' => "s",
+        'z' => "ts",
+        'v' => "f",
+        'w' => "v",
+        'x' => "ks",
+        'c' => "k",
+        'r' if previous.is_some_and(is_vowel) && !next.is_some_and(is_vowel) => "ɐ̯",
+        'r' => "ʁ",
+        c => return c.to_string(),
+    };
+
+    String::from(phonemes)
+}
+
+fn long_vowel(c: char) -> &'static str {
+    match c {
+        'a' => "a",
+        'e' | 'ä' => "e",
+        'i' => "i",
+        'o' => "o",
+        'u' => "u",
+        'ö' => "ø",
+        'ü' | 'y' => "y",
+        _ => "",
+    }
+}
+
+fn is_vowel(c: char) -> bool {
+    matches!(c, 'a' | 'e' | 'i' | 'o' | 'u' | 'ä' | 'ö' | 'ü' | 'y')
+}
+
+/// Rewrites an IPA transcription in SAMPA, symbols without an equivalent are kept.
+fn to_sampa(ipa: &str) -> String {
+    let mut sampa = String::with_capacity(ipa.len());
+    let mut rest = ipa;
+
+    while let Some(c) = rest.chars().next() {
+        match SAMPA.iter().find(|(symbol, _)| rest.starts_with(symbol)) {
+            Some((symbol, replacement)) => {
+                sampa.push_str(replacement);
+                rest = &rest[symbol.len()..];
+            }
+            None => {
+                sampa.push(c);
+                rest = &rest[c.len_utf8()..];
+            }
+        }
+    }
+
+    sampa
+}
+
+#[cfg(test)]
+mod tests {
+    mod transcribe {
+        use crate::phonetic::{transcribe, Alphabet};
+
+        #[test]
+        fn should_apply_german_rules() {
+            assert_eq!(transcribe("Schule", Alphabet::Ipa), "ʃʊlə");
+            assert_eq!(transcribe("ich auch", Alphabet::Ipa), "ɪç aʊ̯x");
+            assert_eq!(transcribe("Tag", Alphabet::Ipa), "tak");
+            assert_eq!(transcribe("Stuhl", Alphabet::Ipa), "ʃtuːl");
+        }
+
+        #[test]
+        fn should_prefer_the_pronunciations_of_the_dictionary() {
+            assert_eq!(transcribe("Meddl!", Alphabet::Ipa), "mɛdl̩!");
+            assert_eq!(transcribe("Meddl!", Alphabet::Sampa), "mEdl=!");
+        }
+    }
+
+    mod golden {
+        use std::fs;
+        use std::path::Path;
+
+        use crate::phonetic::{transcribe, Alphabet};
+
+        /// Compares the transcription of every `tests/golden/phonetic/*.txt` with the `.ipa` and
+        /// `.sampa` files next to it. Run with `UPDATE_GOLDEN=1` to rewrite them.
+        #[test]
+        fn should_match_golden_files() {
+            let directory = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/golden/phonetic");
+            let update = std::env::var_os("UPDATE_GOLDEN").is_some();
+            let mut compared = 0;
+
+            for entry in fs::read_dir(&directory).unwrap() {
+                let path = entry.unwrap().path();
+                if path.extension().is_none_or(|extension| extension != "txt") {
+                    continue;
+                }
+                let text = fs::read_to_string(&path).unwrap();
+
+                for (extension, alphabet) in [("ipa", Alphabet::Ipa), ("sampa", Alphabet::Sampa)] {
+                    let golden = path.with_extension(extension);
+                    let transcription = transcribe(&text, alphabet);
+
+                    if update {
+                        fs::write(&golden, &transcription).unwrap();
+                    }
+                    assert_eq!(transcription, fs::read_to_string(&golden).unwrap(), "{} differs", golden.display());
+                    compared += 1;
+                }
+            }
+
+            assert!(compared > 0);
+        }
+    }
+}
@@ -0,0 +1,114 @@
+use std::sync::OnceLock;
+
+use regex::Regex;
+
+use crate::Translator;
+
+/// printf style conversions (`%s`, `%1$d`, `%.2f`, `%%`) and brace style
+/// interpolations (`{name}`, `{0}`, `{}`).
+const PLACEHOLDER_PATTERN: &str = r"%(?:\d+\$)?[-+ #0]*(?:\d+|\*)?(?:\.(?:\d+|\*))?(?:hh|h|ll|l|L|q|j|z|t)?[diouxXeEfFgGaAcspn%]|\{[A-Za-z0-9_]*\}";
+
+#[derive(Debug, PartialEq)]
+pub(crate) enum Segment<'a> {
+    Text(&'a str),
+    Placeholder(&'a str),
+}
+
+pub(crate) fn placeholder_regex() -> &'static Regex {
+    static PLACEHOLDER: OnceLock<Regex> = OnceLock::new();
+
+    PLACEHOLDER.get_or_init(|| Regex::new(PLACEHOLDER_PATTERN).expect("Could not compile placeholder regex."))
+}
+
+pub(crate) fn split_placeholders<'a>(text: &'a str, regex: &Regex) -> Vec<Segment<'a>> {
+    let mut segments = Vec::new();
+    let mut last = 0;
+
+    for found in regex.find_iter(text) {
+        if found.start() > last {
+            segments.push(Segment::Text(&text[last..found.start()]));
+        }
+        segments.push(Segment::Placeholder(found.as_str()));
+        last = found.end();
+    }
+    if last < text.len() {
+        segments.push(Segment::Text(&text[last..]));
+    }
+
+    segments
+}
+
+/// Translates everything in `text` with `translator` except the placeholders matched by
+/// `regex`, which are copied to the output unchanged.
+pub(crate) fn translate_protected(text: &str, regex: &Regex, translator: &Translator) -> String {
+    split_placeholders(text, regex)
+        .into_iter()
+        .map(|segment| match segment {
+            Segment::Text(text) => translate_padded(text, translator),
+            Segment::Placeholder(placeholder) => String::from(placeholder),
+        })
+        .collect()
+}
+
+/// Like `translate`, but keeps line breaks as well as leading and trailing
+/// whitespace, so the result can be glued back together with its neighbours.
+pub(crate) fn translate_padded(text: &str, translator: &Translator) -> String {
+    text.split('\n')
+        .map(|line| {
+            let trimmed = line.trim();
+            if trimmed.is_empty() {
+                return String::from(line);
+            }
+            let start = line.find(trimmed).unwrap_or(0);
+            let end = start + trimmed.len();
+
+            format!("{}{}{}", &line[..start], translator.translate(trimmed), &line[end..])
+        })
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    mod split_placeholders {
+        use crate::placeholders::{placeholder_regex, split_placeholders, Segment};
+
+        #[test]
+        fn should_split_printf_and_brace_placeholders() {
+            assert_eq!(
+                split_placeholders("%1$s hat {count} Dateien zu %.2f%%", placeholder_regex()),
+                vec![
+                    Segment::Placeholder("%1$s"),
+                    Segment::Text(" hat "),
+                    Segment::Placeholder("{count}"),
+                    Segment::Text(" Dateien zu "),
+                    Segment::Placeholder("%.2f"),
+                    Segment::Placeholder("%%"),
+                ]
+            );
+        }
+    }
+
+    mod translate_protected {
+        use crate::placeholders::{placeholder_regex, translate_protected};
+        use crate::Translator;
+
+        #[test]
+        fn should_keep_placeholders() {
+            assert_eq!(
+                translate_protected("In Rust {name} programmiert %s", placeholder_regex(), &Translator::new().with_seed(1)),
+                "In Rusd {name} brogrammierd %s"
+            );
+        }
+    }
+
+    mod translate_padded {
+        use crate::placeholders::translate_padded;
+        use crate::Translator;
+
+        #[test]
+        fn should_keep_surrounding_whitespace_and_line_breaks() {
+            assert_eq!(translate_padded(" Rust \nprogrammiert ", &Translator::new().with_seed(1)), " Rusd \nbrogrammierd ");
+        }
+    }
+}
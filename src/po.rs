@@ -0,0 +1,319 @@
+//! Translation of gettext `.po` catalogues.
+//!
+//! Every `msgstr` (or the `msgid` if the `msgstr` is still empty) is translated, while
+//! comments, flags, contexts, plural forms and the catalogue header are kept as they are.
+//! printf (`%s`, `%1$d`) and brace (`{name}`) placeholders are never touched.
+//!
+//! ```
+//! use meddl_translate::po::translate_po_with;
+//! use meddl_translate::Translator;
+//!
+//! let catalogue = "msgid \"Rust programmiert\"\nmsgstr \"\"\n";
+//!
+//! assert_eq!(
+//!     translate_po_with(&Translator::new().with_seed(1), catalogue),
+//!     "msgid \"Rust programmiert\"\nmsgstr \"Rusd brogrammierd\"\n"
+//! );
+//! ```
+
+use regex::Regex;
+
+use crate::placeholders::{placeholder_regex, translate_protected};
+use crate::Translator;
+
+struct Field {
+    keyword: String,
+    value: String,
+    lines: Vec<String>,
+}
+
+#[derive(Default)]
+struct Entry {
+    comments: Vec<String>,
+    fields: Vec<Field>,
+}
+
+impl Entry {
+    fn is_empty(&self) -> bool {
+        self.comments.is_empty() && self.fields.is_empty()
+    }
+
+    fn has_msgstr(&self) -> bool {
+        self.fields.iter().any(|field| field.keyword.starts_with("msgstr"))
+    }
+
+    fn value_of(&self, keyword: &str) -> Option<&str> {
+        self.fields
+            .iter()
+            .find(|field| field.keyword == keyword)
+            .map(|field| field.value.as_str())
+    }
+}
+
+/// Translates a gettext `.po` catalogue and returns the new catalogue.
+///
+/// # Example
+///
+/// ```
+/// let catalogue = "#, c-format\nmsgid \"%d files\"\nmsgstr \"%d Dateien\"\n";
+/// let oger = meddl_translate::po::translate_po(catalogue);
+///
+/// // only the msgstr is translated, its placeholder is kept.
+/// assert!(oger.starts_with("#, c-format\nmsgid \"%d files\"\nmsgstr \"%d "));
+/// ```
+pub fn translate_po(catalogue: &str) -> String {
+    translate_po_with(&Translator::new(), catalogue)
+}
+
+/// Like [`translate_po`], but translates with `translator`, e.g. for using a profile.
+pub fn translate_po_with(translator: &Translator, catalogue: &str) -> String {
+    let placeholders = placeholder_regex();
+    let mut output = String::new();
+    let mut entry = Entry::default();
+
+    for line in catalogue.lines() {
+        let trimmed = line.trim();
+
+        if trimmed.is_empty() {
+            write_entry(&mut output, &entry, placeholders, translator);
+            entry = Entry::default();
+            output.push_str(line);
+            output.push('\n');
+        } else if trimmed.starts_with('#') {
+            if !entry.fields.is_empty() {
+                write_entry(&mut output, &entry, placeholders, translator);
+                entry = Entry::default();
+            }
+            entry.comments.push(String::from(line));
+        } else if trimmed.starts_with('"') && !entry.fields.is_empty() {
+            let field = entry.fields.last_mut().unwrap();
+            field.value.push_str(&unescape(unquote(trimmed)));
+            field.lines.push(String::from(line));
+        } else if let Some((keyword, string)) = split_keyword(trimmed) {
+            if (keyword == "msgid" || keyword == "msgctxt") && entry.has_msgstr() {
+                write_entry(&mut output, &entry, placeholders, translator);
+                entry = Entry::default();
+            }
+            entry.fields.push(Field {
+                keyword: String::from(keyword),
+                value: unescape(unquote(string)),
+                lines: vec![String::from(line)],
+            });
+        } else {
+            // not something we understand, pass it through untouched.
+            write_entry(&mut output, &entry, placeholders, translator);
+            entry = Entry::default();
+            output.push_str(line);
+            output.push('\n');
+        }
+    }
+    write_entry(&mut output, &entry, placeholders, translator);
+
+    output
+}
+
+fn write_entry(output: &mut String, entry: &Entry, placeholders: &Regex, translator: &Translator) {
+    if entry.is_empty() {
+        return;
+    }
+
+    for comment in &entry.comments {
+        output.push_str(comment);
+        output.push('\n');
+    }
+
+    // the header is stored in the entry with an empty msgid.
+    let is_header = entry.value_of("msgid") == Some("") && entry.value_of("msgctxt").is_none();
+
+    for field in &entry.fields {
+        if is_header || !field.keyword.starts_with("msgstr") {
+            for line in &field.lines {
+                output.push_str(line);
+                output.push('\n');
+            }
+            continue;
+        }
+
+        let source = if !field.value.is_empty() {
+            field.value.as_str()
+        } else if field.keyword == "msgstr" || field.keyword == "msgstr[0]" {
+            entry.value_of("msgid").unwrap_or("")
+        } else {
+            entry
+                .value_of("msgid_plural")
+                .or_else(|| entry.value_of("msgid"))
+                .unwrap_or("")
+        };
+
+        let translated = translate_protected(source, placeholders, translator);
+        write_field(output, &field.keyword, &translated, field.lines.len() > 1);
+    }
+}
+
+fn write_field(output: &mut String, keyword: &str, value: &str, multiline: bool) {
+    let pieces: Vec<&str> = value.split_inclusive('\n').collect();
+
+    if !multiline && pieces.len() <= 1 {
+        output.push_str(&format!("{} \"{}\"\n", keyword, escape(value)));
+        return;
+    }
+
+    output.push_str(&format!("{} \"\"\n", keyword));
+    for piece in pieces {
+        output.push_str(&format!("\"{}\"\n", escape(piece)));
+    }
+}
+
+fn split_keyword(line: &str) -> Option<(&str, &str)> {
+    let position = line.find(char::is_whitespace)?;
+    let (keyword, rest) = line.split_at(position);
+    let rest = rest.trim_start();
+
+    if keyword.starts_with("msg") && rest.starts_with('"') {
+        return Some((keyword, rest));
+    }
+
+    None
+}
+
+fn unquote(string: &str) -> &str {
+    let string = string.strip_prefix('"').unwrap_or(string);
+    string.strip_suffix('"').unwrap_or(string)
+}
+
+fn unescape(string: &str) -> String {
+    let mut unescaped = String::new();
+    let mut chars = string.chars();
+
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            unescaped.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('n') => unescaped.push('\n'),
+            Some('t') => unescaped.push('\t'),
+            Some('r') => unescaped.push('\r'),
+            Some('"') => unescaped.push('"'),
+            Some('\\') => unescaped.push('\\'),
+            Some(other) => {
+                unescaped.push('\\');
+                unescaped.push(other);
+            }
+            None => unescaped.push('\\'),
+        }
+    }
+
+    unescaped
+}
+
+fn escape(string: &str) -> String {
+    let mut escaped = String::new();
+
+    for c in string.chars() {
+        match c {
+            '\n' => escaped.push_str("\\n"),
+            '\t' => escaped.push_str("\\t"),
+            '\r' => escaped.push_str("\\r"),
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            _ => escaped.push(c),
+        }
+    }
+
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    mod translate_po {
+        use crate::po::translate_po_with;
+        use crate::Translator;
+
+        fn translator() -> Translator {
+            Translator::new().with_seed(1)
+        }
+
+        #[test]
+        fn should_translate_msgid_when_msgstr_is_empty() {
+            let catalogue = "msgid \"Rust programmiert\"\nmsgstr \"\"\n";
+
+            assert_eq!(translate_po_with(&translator(), catalogue), "msgid \"Rust programmiert\"\nmsgstr \"Rusd brogrammierd\"\n");
+        }
+
+        #[test]
+        fn should_translate_existing_msgstr() {
+            let catalogue = "msgid \"Written in Rust\"\nmsgstr \"In Rust programmiert\"\n";
+
+            assert_eq!(translate_po_with(&translator(), catalogue), "msgid \"Written in Rust\"\nmsgstr \"In Rusd brogrammierd\"\n");
+        }
+
+        #[test]
+        fn should_keep_header_and_comments() {
+            let catalogue = "# Deutsche Übersetzung\nmsgid \"\"\nmsgstr \"\"\n\"Language: de\\n\"\n\n#: src/main.rs:12\n#, c-format\nmsgid \"Rust %s\"\nmsgstr \"\"\n";
+
+            assert_eq!(
+                translate_po_with(&translator(), catalogue),
+                "# Deutsche Übersetzung\nmsgid \"\"\nmsgstr \"\"\n\"Language: de\\n\"\n\n#: src/main.rs:12\n#, c-format\nmsgid \"Rust %s\"\nmsgstr \"Rusd %s\"\n"
+            );
+        }
+
+        #[test]
+        fn should_translate_plural_forms() {
+            let catalogue = "msgid \"{n} Rust\"\nmsgid_plural \"{n} programmiert\"\nmsgstr[0] \"\"\nmsgstr[1] \"\"\n";
+
+            assert_eq!(
+                translate_po_with(&translator(), catalogue),
+                "msgid \"{n} Rust\"\nmsgid_plural \"{n} programmiert\"\nmsgstr[0] \"{n} Rusd\"\nmsgstr[1] \"{n} brogrammierd\"\n"
+            );
+        }
+
+        #[test]
+        fn should_keep_multiline_strings_multiline() {
+            let catalogue = "msgid \"\"\n\"Rust\\n\"\n\"programmiert\"\nmsgstr \"\"\n";
+
+            assert_eq!(
+                translate_po_with(&translator(), catalogue),
+                "msgid \"\"\n\"Rust\\n\"\n\"programmiert\"\nmsgstr \"\"\n\"Rusd\\n\"\n\"brogrammierd\"\n"
+            );
+        }
+
+        #[test]
+        fn should_keep_msgctxt_and_escapes() {
+            let catalogue = "msgctxt \"menu\"\nmsgid \"Rust\\tprogrammiert\"\nmsgstr \"\"\n";
+
+            assert_eq!(
+                translate_po_with(&translator(), catalogue),
+                "msgctxt \"menu\"\nmsgid \"Rust\\tprogrammiert\"\nmsgstr \"Rusd\\tbrogrammierd\"\n"
+            );
+        }
+    }
+
+    mod translate_po_with {
+        use crate::po::translate_po_with;
+        use crate::{Dictionary, Translator};
+
+        #[test]
+        fn should_translate_with_the_given_translator() {
+            let dictionary = Dictionary::from_json(r#"{"translations": {"ich": ["i"]}}"#).unwrap();
+            let translator = Translator::new().with_dictionary(dictionary);
+
+            assert_eq!(
+                translate_po_with(&translator, "msgid \"ich %s\"\nmsgstr \"\"\n"),
+                "msgid \"ich %s\"\nmsgstr \"i %s\"\n"
+            );
+        }
+    }
+
+    mod unescape {
+        use crate::po::{escape, unescape};
+
+        #[test]
+        fn should_roundtrip_escapes() {
+            let escaped = "Zeile\\n\\t\\\"zitiert\\\" \\\\";
+
+            assert_eq!(unescape(escaped), "Zeile\n\t\"zitiert\" \\");
+            assert_eq!(escape(&unescape(escaped)), escaped);
+        }
+    }
+}
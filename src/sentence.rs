@@ -0,0 +1,175 @@
+//! Splitting text into sentences.
+//!
+//! A sentence ends with a word ending in `.`, `!` or `?`, optionally followed by closing quotes
+//! or brackets. Dots of German abbreviations (`z.B.`, `Dr.`, `usw.`), initials and ordinals
+//! (`am 3. Oktober`, `3.10.`) don't end a sentence, dots within decimals (`3.5`) never do.
+//!
+//! # Example
+//!
+//! ```
+//! use meddl_translate::sentence::{split, Sentence};
+//!
+//! let text = "Dr. Müller kommt am 3. Mai. Kommst du auch?";
+//!
+//! assert_eq!(split(text), vec![
+//!     Sentence { span: 0..28, terminator: Some('.') },
+//!     Sentence { span: 29..44, terminator: Some('?') },
+//! ]);
+//! ```
+
+use std::ops::Range;
+
+/// Abbreviations ending with a dot that don't end a sentence, lowercase. Abbreviations made of
+/// single letters like `z.B.` or `d.h.` are recognised without being listed.
+const ABBREVIATIONS: [&str; 46] = [
+    "abb.", "abs.", "allg.", "apr.", "aug.", "bd.", "bspw.", "bzgl.", "bzw.", "ca.", "dez.", "di.", "do.", "dr.",
+    "etc.", "evtl.", "feb.", "fr.", "geb.", "ggf.", "hr.", "inkl.", "jan.", "jh.", "jul.", "jun.", "mi.",
+    "min.", "mind.", "mio.", "mo.", "mrd.", "nov.", "nr.", "okt.", "prof.", "sa.", "sept.", "sog.", "st.",
+    "std.", "str.", "tel.", "usw.", "vgl.", "zzgl.",
+];
+
+/// Characters that may follow the punctuation ending a sentence.
+const CLOSING: [char; 8] = ['"', '\'', ')', ']', '»', '«', '“', '”'];
+
+/// A sentence of a text.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Sentence {
+    /// Byte range in the text, without the whitespace around the sentence.
+    pub span: Range<usize>,
+    /// The `.`, `!` or `?` ending the sentence, `None` if the text ends without one.
+    pub terminator: Option<char>,
+}
+
+/// Splits `text` into sentences, whitespace between them belongs to none of them.
+pub fn split(text: &str) -> Vec<Sentence> {
+    let words = words(text);
+    let mut sentences = Vec::new();
+    let mut start = None;
+
+    for (index, &(word_start, word)) in words.iter().enumerate() {
+        let sentence_start = *start.get_or_insert(word_start);
+        let next = words.get(index + 1).map(|&(_, next)| next);
+
+        if let Some(terminator) = terminator(word, next) {
+            sentences.push(Sentence {
+                span: sentence_start..word_start + word.len(),
+                terminator: Some(terminator),
+            });
+            start = None;
+        }
+    }
+    if let (Some(start), Some(&(last_start, last))) = (start, words.last()) {
+        sentences.push(Sentence {
+            span: start..last_start + last.len(),
+            terminator: None,
+        });
+    }
+
+    sentences
+}
+
+/// Whether `word` ends a sentence, `next` being the word after it.
+pub(crate) fn ends_sentence(word: &str, next: Option<&str>) -> bool {
+    terminator(word, next).is_some()
+}
+
+/// Whether `word` is an abbreviation whose dot doesn't end a sentence.
+pub(crate) fn is_abbreviation(word: &str) -> bool {
+    let lowercase = word.to_lowercase();
+    if ABBREVIATIONS.contains(&lowercase.as_str()) {
+        return true;
+    }
+
+    // single letters followed by a dot each, like "z.B.", "u.v.m." or initials.
+    let chars: Vec<char> = word.chars().collect();
+    !chars.is_empty()
+        && chars.len().is_multiple_of(2)
+        && chars
+            .chunks(2)
+            .all(|pair| pair[0].is_alphabetic() && pair[1] == '.')
+}
+
+/// Whether `word` is a number followed by a dot, like the ordinal `3.` or the date `3.10.`.
+fn is_ordinal(word: &str) -> bool {
+    word.strip_suffix('.').is_some_and(|number| {
+        !number.is_empty()
+            && number.split('.').all(|part| !part.is_empty() && part.chars().all(|c| c.is_ascii_digit()))
+    })
+}
+
+fn terminator(word: &str, next: Option<&str>) -> Option<char> {
+    let word = word.trim_end_matches(CLOSING);
+
+    match word.chars().last()? {
+        '!' => Some('!'),
+        '?' => Some('?'),
+        '.' if is_abbreviation(word) => None,
+        // an ordinal at the very end can only be a number ending the sentence.
+        '.' if is_ordinal(word) && next.is_some() => None,
+        '.' => Some('.'),
+        _ => None,
+    }
+}
+
+fn words(text: &str) -> Vec<(usize, &str)> {
+    let mut words = Vec::new();
+    let mut start = None;
+
+    for (index, c) in text.char_indices() {
+        match (c.is_whitespace(), start) {
+            (true, Some(word_start)) => {
+                words.push((word_start, &text[word_start..index]));
+                start = None;
+            }
+            (false, None) => start = Some(index),
+            _ => {}
+        }
+    }
+    if let Some(word_start) = start {
+        words.push((word_start, &text[word_start..]));
+    }
+
+    words
+}
+
+#[cfg(test)]
+mod tests {
+    mod split {
+        use crate::sentence::{split, Sentence};
+
+        fn spans(text: &str) -> Vec<&str> {
+            split(text).into_iter().map(|sentence| &text[sentence.span]).collect()
+        }
+
+        #[test]
+        fn should_split_after_terminators() {
+            assert_eq!(spans("Meddl Leude! Wie geht's? Gut.  Danke"), vec!["Meddl Leude!", "Wie geht's?", "Gut.", "Danke"]);
+        }
+
+        #[test]
+        fn should_not_split_after_abbreviations() {
+            assert_eq!(spans("Das ist z.B. Dr. Müller usw. Ende."), vec!["Das ist z.B. Dr. Müller usw. Ende."]);
+        }
+
+        #[test]
+        fn should_not_split_at_ordinals_dates_and_decimals() {
+            assert_eq!(spans("Am 3. Mai bzw. am 3.10. kostet es 3.5 Euro. Oder 4."), vec![
+                "Am 3. Mai bzw. am 3.10. kostet es 3.5 Euro.",
+                "Oder 4.",
+            ]);
+        }
+
+        #[test]
+        fn should_include_closing_quotes() {
+            assert_eq!(split("\"Wirklich?\" Ja."), vec![
+                Sentence { span: 0..11, terminator: Some('?') },
+                Sentence { span: 12..15, terminator: Some('.') },
+            ]);
+        }
+
+        #[test]
+        fn should_return_nothing_for_whitespace() {
+            assert_eq!(split(" \n "), vec![]);
+        }
+    }
+}
@@ -0,0 +1,186 @@
+//! SSML output for text-to-speech engines like espeak or piper.
+//!
+//! Every sentence is wrapped in `<s>`, interludes become a `<break>` and stage directions
+//! like `*ferstellt Stimme:*` aren't read out, instead the rest of the sentence is spoken with
+//! different prosody.
+//!
+//! # Example
+//!
+//! ```
+//! use meddl_translate::{ssml, Dictionary, Translator};
+//!
+//! let dictionary = Dictionary::from_json(r#"{"quotationMark": "*flüstert* "}"#).unwrap();
+//! let translator = Translator::new().with_dictionary(dictionary);
+//! let original = "Hallo. \"pssst & Ruhe!";
+//!
+//! assert_eq!(
+//!     ssml::render(original, &translator.translate_tokens(original)),
+//!     "<speak><s>Hallo.</s><s><prosody volume=\"x-soft\">pssst &amp; Ruhe!</prosody></s></speak>"
+//! );
+//! ```
+
+use crate::{sentence, Token, TokenKind};
+
+/// Prosody of stage directions containing the word on the left, the first match is used.
+///
+/// Directions are translated like the rest of the text, so the twisted spellings are listed too.
+const PROSODY: [(&str, &str); 7] = [
+    ("flüster", "volume=\"x-soft\""),
+    ("flüsder", "volume=\"x-soft\""),
+    ("schrei", "volume=\"x-loud\""),
+    ("brüll", "volume=\"x-loud\""),
+    ("stimme", "pitch=\"+25%\""),
+    ("sdimme", "pitch=\"+25%\""),
+    ("schnauf", "rate=\"slow\""),
+];
+
+/// Prosody of all other stage directions.
+const DEFAULT_PROSODY: &str = "pitch=\"-10%\"";
+
+/// Pause replacing an interlude.
+const BREAK: &str = "<break time=\"1s\"/>";
+
+/// Renders the `tokens` translated from `original` as an SSML document.
+pub fn render(original: &str, tokens: &[Token]) -> String {
+    let sentences = sentence::split(original);
+    let mut grouped: Vec<Vec<&Token>> = vec![Vec::new(); sentences.len().max(1)];
+
+    for token in tokens {
+        let index = sentences.partition_point(|sentence| sentence.span.start <= token.span.start);
+        grouped[index.saturating_sub(1)].push(token);
+    }
+
+    let mut ssml = String::from("<speak>");
+    for sentence in grouped {
+        let content = render_sentence(&sentence);
+        if !content.is_empty() {
+            ssml.push_str(&format!("<s>{}</s>", content));
+        }
+    }
+    ssml.push_str("</speak>");
+
+    ssml
+}
+
+fn render_sentence(tokens: &[&Token]) -> String {
+    let mut content = String::new();
+    let mut open_prosody = false;
+
+    for token in tokens {
+        if token.kind == TokenKind::Interlude {
+            content.push_str(BREAK);
+            continue;
+        }
+
+        let mut rest = token.text.as_str();
+        while let Some((before, direction, after)) = stage_direction(rest) {
+            content.push_str(&escape(before));
+            if open_prosody {
+                content.push_str("</prosody>");
+            }
+            content.push_str(&format!("<prosody {}>", prosody(direction)));
+            open_prosody = true;
+            // the space after a stage direction isn't spoken.
+            rest = after.trim_start();
+        }
+        content.push_str(&escape(rest));
+    }
+
+    let mut content = String::from(content.trim());
+    if open_prosody {
+        content.push_str("</prosody>");
+    }
+
+    content
+}
+
+/// Splits `text` at the first `*direction*` into the text before, the direction and the rest.
+fn stage_direction(text: &str) -> Option<(&str, &str, &str)> {
+    let start = text.find('*')?;
+    let length = text[start + 1..].find('*')?;
+    let end = start + 1 + length;
+
+    Some((&text[..start], &text[start + 1..end], &text[end + 1..]))
+}
+
+fn prosody(direction: &str) -> &'static str {
+    let direction = direction.to_lowercase();
+
+    PROSODY
+        .iter()
+        .find(|(word, _)| direction.contains(word))
+        .map_or(DEFAULT_PROSODY, |(_, prosody)| prosody)
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+#[cfg(test)]
+mod tests {
+    mod render {
+        use crate::ssml::render;
+        use crate::{Token, TokenKind};
+
+        #[test]
+        fn should_wrap_sentences() {
+            let tokens = vec![
+                Token::new(0..4, "Rusd", TokenKind::Word),
+                Token::new(4..5, "!", TokenKind::Suffix),
+                Token::new(5..6, " ", TokenKind::Whitespace),
+                Token::new(6..10, "Rusd", TokenKind::Word),
+            ];
+
+            assert_eq!(
+                render("Rust! Rust", &tokens),
+                "<speak><s>Rusd!</s><s>Rusd</s></speak>"
+            );
+        }
+
+        #[test]
+        fn should_replace_interludes_with_breaks() {
+            let tokens = vec![
+                Token::new(0..4, "Rusd", TokenKind::Word),
+                Token::new(4..4, "ämmmm... *rendert* ...", TokenKind::Interlude),
+                Token::new(4..5, " ", TokenKind::Whitespace),
+                Token::new(5..9, "Rusd", TokenKind::Word),
+            ];
+
+            assert_eq!(
+                render("Rust Rust", &tokens),
+                "<speak><s>Rusd<break time=\"1s\"/> Rusd</s></speak>"
+            );
+        }
+
+        #[test]
+        fn should_turn_stage_directions_into_prosody() {
+            let tokens = vec![
+                Token::new(0..5, "*ferstellt Stimme:* \"Äh, Rusd", TokenKind::Word),
+                Token::new(5..6, " ", TokenKind::Whitespace),
+                Token::new(6..19, "*schreit* <3", TokenKind::Word),
+            ];
+
+            assert_eq!(
+                render("\"Rust \"love <3", &tokens),
+                "<speak><s><prosody pitch=\"+25%\">\"Äh, Rusd </prosody><prosody volume=\"x-loud\">&lt;3</prosody></s></speak>"
+            );
+        }
+
+        #[test]
+        fn should_recognise_the_translated_quotation_mark() {
+            let ssml = crate::Translator::new().with_seed(1).translate_ssml("\"Rust mag ich");
+
+            assert_eq!(
+                ssml,
+                "<speak><s><prosody pitch=\"+25%\">\"äh, rusd mag ich</prosody></s></speak>"
+            );
+        }
+
+        #[test]
+        fn should_render_empty_input() {
+            assert_eq!(render("", &[]), "<speak></speak>");
+        }
+    }
+}
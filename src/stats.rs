@@ -0,0 +1,281 @@
+//! Coverage statistics of a dictionary for a corpus, to find out where it is worth growing.
+//!
+//! The corpus goes through the same pipeline as a translation, so every word is counted the way
+//! the translator treated it: translated by the `translations` section, ignored, twisted by the
+//! rule-based steps alone or left untranslated. A phrase of the `translations` counts as a single
+//! word, numbers and abbreviations aren't counted.
+//!
+//! # Example
+//!
+//! ```
+//! use meddl_translate::stats::stats;
+//!
+//! let stats = stats("Hallo Leute, hallo Welt");
+//!
+//! assert_eq!(stats.words, 4);
+//! assert_eq!(stats.translated[0].word, "Hallo");
+//! println!("{}", stats.table(10));
+//! ```
+
+use std::collections::HashMap;
+use std::fmt::Write;
+
+use serde::Serialize;
+
+use crate::{normalize, Dictionary, Step, Translator};
+
+/// How often a word was seen.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct WordCount {
+    pub word: String,
+    pub count: usize,
+}
+
+/// How often an entry of the `rules` section changed a word.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct RuleCount {
+    pub pattern: String,
+    pub replacement: String,
+    pub count: usize,
+}
+
+/// The result of [`stats`], all word lists are sorted by how often the word was seen.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct Stats {
+    /// Number of words counted.
+    pub words: usize,
+    /// Words found in the `translations` section.
+    pub translated: Vec<WordCount>,
+    /// Words changed by beginnings, twisted chars, endings or rules only.
+    pub twisted: Vec<WordCount>,
+    /// Words that are ignored.
+    pub ignored: Vec<WordCount>,
+    /// Words that came out the way they went in, apart from their case.
+    pub untranslated: Vec<WordCount>,
+    /// Every rule in the order of the dictionary, including those that never fired.
+    pub rules: Vec<RuleCount>,
+}
+
+impl Stats {
+    /// The statistics as pretty-printed JSON.
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).expect("Could not serialize stats.")
+    }
+
+    /// The statistics as a plain text table, listing at most `limit` words of each kind.
+    pub fn table(&self, limit: usize) -> String {
+        let mut table = String::new();
+        let share = |words: &[WordCount]| {
+            let count = total(words);
+            match self.words {
+                0 => (count, 0.0),
+                all => (count, count as f64 * 100.0 / all as f64),
+            }
+        };
+
+        let _ = writeln!(table, "{:<14}{:>8}", "words", self.words);
+        for (name, words) in self.sections() {
+            let (count, percent) = share(words);
+            let _ = writeln!(table, "{:<14}{:>8}{:>7.1}%", name, count, percent);
+        }
+
+        for (name, words) in self.sections() {
+            if words.is_empty() {
+                continue;
+            }
+            let _ = writeln!(table, "\nmost frequent {} words", name);
+            for word in words.iter().take(limit) {
+                let _ = writeln!(table, "{:>8}  {}", word.count, word.word);
+            }
+        }
+
+        if !self.rules.is_empty() {
+            let _ = writeln!(table, "\nrules");
+            for rule in &self.rules {
+                let _ = writeln!(table, "{:>8}  {} → {}", rule.count, rule.pattern, rule.replacement);
+            }
+        }
+
+        table
+    }
+
+    fn sections(&self) -> [(&'static str, &[WordCount]); 4] {
+        [
+            ("translated", &self.translated),
+            ("twisted", &self.twisted),
+            ("ignored", &self.ignored),
+            ("untranslated", &self.untranslated),
+        ]
+    }
+}
+
+/// Collects the statistics of `corpus` for the built-in dictionary.
+pub fn stats(corpus: &str) -> Stats {
+    Translator::new().stats(corpus)
+}
+
+/// Collects the statistics of `corpus` for `dictionary`.
+///
+/// Rules are counted for translated words as well, for the translation that was picked. Use
+/// [`Translator::stats`] to count with the options and the seed of a translator.
+///
+/// # Example
+///
+/// ```
+/// use meddl_translate::stats::stats_with;
+/// use meddl_translate::Dictionary;
+///
+/// let dictionary = Dictionary::from_json(r#"{"rules": [{"pattern": "ung$", "replacement": "ungg"}]}"#).unwrap();
+/// let stats = stats_with("Zeitung, Rust!", &dictionary);
+///
+/// assert_eq!(stats.twisted[0].word, "Zeitung");
+/// assert_eq!(stats.untranslated[0].word, "Rust");
+/// assert_eq!(stats.rules[0].count, 1);
+/// ```
+pub fn stats_with(corpus: &str, dictionary: &Dictionary) -> Stats {
+    Translator::new().with_dictionary(dictionary.clone()).stats(corpus)
+}
+
+/// Translates `corpus` with `translator`, whose dictionary is `dictionary`, counting every step.
+pub(crate) fn collect(translator: &Translator, dictionary: &Dictionary, corpus: &str) -> Stats {
+    let mut translated = HashMap::new();
+    let mut twisted = HashMap::new();
+    let mut ignored = HashMap::new();
+    let mut untranslated = HashMap::new();
+    let mut fired = vec![0; dictionary.rules.len()];
+
+    translator.translate_observed(corpus, &mut |step| {
+        let (counts, word) = match step {
+            Step::Translated(word) => (&mut translated, word),
+            Step::Ignored(word) => (&mut ignored, word),
+            Step::Twisted(word) => (&mut twisted, word),
+            Step::Untouched(word) => (&mut untranslated, word),
+            Step::Rule(index) => {
+                fired[index] += 1;
+                return;
+            }
+        };
+
+        // quotation marks and symbols like "&" are passed on as words as well.
+        let word = normalize::nfc(word.trim_matches(|c: char| !c.is_alphanumeric()));
+        if !word.is_empty() {
+            *counts.entry(word.into_owned()).or_insert(0) += 1;
+        }
+    });
+
+    Stats {
+        words: [&translated, &twisted, &ignored, &untranslated].iter().flat_map(|counts| counts.values()).sum(),
+        translated: sorted(translated),
+        twisted: sorted(twisted),
+        ignored: sorted(ignored),
+        untranslated: sorted(untranslated),
+        rules: dictionary
+            .rules
+            .sources
+            .iter()
+            .zip(fired)
+            .map(|(source, count)| RuleCount {
+                pattern: String::from(source.pattern.as_ref()),
+                replacement: String::from(source.replacement.as_ref()),
+                count,
+            })
+            .collect(),
+    }
+}
+
+/// The most frequent words first, words seen equally often in alphabetical order.
+fn sorted(counts: HashMap<String, usize>) -> Vec<WordCount> {
+    let mut words: Vec<WordCount> = counts.into_iter().map(|(word, count)| WordCount { word, count }).collect();
+    words.sort_by(|a, b| b.count.cmp(&a.count).then_with(|| a.word.cmp(&b.word)));
+
+    words
+}
+
+fn total(words: &[WordCount]) -> usize {
+    words.iter().map(|word| word.count).sum()
+}
+
+#[cfg(test)]
+mod tests {
+    mod stats_with {
+        use crate::stats::{stats_with, WordCount};
+        use crate::Dictionary;
+
+        fn dictionary() -> Dictionary {
+            Dictionary::from_json(
+                r#"{
+                    "translations": {"Hallo": ["Meddl"]},
+                    "ignored": ["Rust"],
+                    "twistedChars": [["t", "d"]],
+                    "rules": [{"pattern": "^m", "replacement": "M"}, {"pattern": "x", "replacement": "ggs"}]
+                }"#,
+            )
+            .unwrap()
+        }
+
+        #[test]
+        fn should_sort_words_by_kind_and_frequency() {
+            let stats = stats_with("Hallo Welt! Hallo Rust, hallo z.B. 42 Leute.", &dictionary());
+
+            assert_eq!(stats.words, 6);
+            assert_eq!(stats.translated, vec![WordCount { word: String::from("Hallo"), count: 2 }]);
+            assert_eq!(stats.twisted[0].word, "Leute");
+            assert_eq!(stats.twisted[1].word, "Welt");
+            assert_eq!(stats.ignored[0].word, "Rust");
+            assert_eq!(stats.untranslated[0].word, "hallo");
+        }
+
+        #[test]
+        fn should_not_count_words_changing_their_case_only_as_twisted() {
+            let stats = stats_with("HTML HTML und CSS", &dictionary());
+
+            assert_eq!(stats.twisted, vec![WordCount { word: String::from("HTML"), count: 2 }]);
+            assert_eq!(stats.untranslated, vec![
+                WordCount { word: String::from("CSS"), count: 1 },
+                WordCount { word: String::from("und"), count: 1 },
+            ]);
+        }
+
+        #[test]
+        fn should_count_rules_that_fired() {
+            let stats = stats_with("Hallo hallo", &dictionary());
+
+            assert_eq!(stats.rules[0].count, 1);
+            assert_eq!(stats.rules[1].count, 0);
+        }
+    }
+
+    mod collect {
+        use crate::stats::WordCount;
+        use crate::{Dictionary, Translator};
+
+        #[test]
+        fn should_count_words_the_way_the_translator_treats_them() {
+            let dictionary = Dictionary::from_json(r#"{"translations": {"guten Morgen": ["Meddl"], "schön": ["schee"]}}"#).unwrap();
+            let translator = Translator::new().with_dictionary(dictionary).with_transliteration();
+            let stats = translator.stats("Guten Morgen, schoen und 3. Mai");
+
+            assert_eq!(stats.words, 4);
+            assert_eq!(stats.translated, vec![
+                WordCount { word: String::from("Guten Morgen"), count: 1 },
+                WordCount { word: String::from("schoen"), count: 1 },
+            ]);
+            assert_eq!(stats.untranslated[0].word, "Mai");
+        }
+    }
+
+    mod table {
+        use crate::stats::stats_with;
+        use crate::Dictionary;
+
+        #[test]
+        fn should_list_the_most_frequent_words() {
+            let dictionary = Dictionary::from_json("{}").unwrap();
+            let table = stats_with("Rust Rust Go", &dictionary).table(1);
+
+            assert!(table.contains("untranslated         3  100.0%"));
+            assert!(table.contains("       2  Rust\n"));
+            assert!(!table.contains("Go"));
+        }
+    }
+}
@@ -0,0 +1,146 @@
+//! Translation of arbitrarily large inputs without loading them into memory.
+//!
+//! Input is read in pieces and translated line by line. A line longer than the internal
+//! buffer is split at its last space, so memory usage stays bounded no matter how large
+//! the input is, and multibyte characters are never split.
+
+use std::io::{self, BufRead, Write};
+
+use crate::placeholders::translate_padded;
+use crate::Translator;
+
+/// Upper bound of bytes kept in memory before a piece is translated.
+const MAX_CHUNK: usize = 64 * 1024;
+
+/// Translates everything `reader` yields and writes the result to `writer`.
+///
+/// # Example
+///
+/// ```
+/// use meddl_translate::stream::translate_stream_with;
+/// use meddl_translate::Translator;
+///
+/// let input = "Rust programmiert\nRust".as_bytes();
+/// let mut output = Vec::new();
+///
+/// translate_stream_with(&Translator::new().with_seed(1), input, &mut output).unwrap();
+///
+/// assert_eq!(String::from_utf8(output).unwrap(), "Rusd brogrammierd\nRusd");
+/// ```
+pub fn translate_stream<R: BufRead, W: Write>(reader: R, writer: W) -> io::Result<()> {
+    translate_stream_chunked(&Translator::new(), reader, writer, MAX_CHUNK)
+}
+
+/// Like [`translate_stream`], but translates with `translator`, e.g. for using a profile.
+pub fn translate_stream_with<R: BufRead, W: Write>(translator: &Translator, reader: R, writer: W) -> io::Result<()> {
+    translate_stream_chunked(translator, reader, writer, MAX_CHUNK)
+}
+
+fn translate_stream_chunked<R: BufRead, W: Write>(
+    translator: &Translator,
+    mut reader: R,
+    mut writer: W,
+    max_chunk: usize,
+) -> io::Result<()> {
+    let mut buffer: Vec<u8> = Vec::new();
+
+    loop {
+        let read = {
+            let data = reader.fill_buf()?;
+            let take = data.len().min(max_chunk.saturating_sub(buffer.len()).max(1));
+            buffer.extend_from_slice(&data[..take]);
+            take
+        };
+        reader.consume(read);
+
+        if read == 0 {
+            write_translated(translator, &mut writer, &buffer)?;
+            return writer.flush();
+        }
+
+        if let Some(cut) = find_cut(&buffer, max_chunk)? {
+            write_translated(translator, &mut writer, &buffer[..cut])?;
+            buffer.drain(..cut);
+        }
+    }
+}
+
+/// Finds the position up to which `buffer` can be translated without cutting through a word,
+/// or `None` if more input is needed.
+fn find_cut(buffer: &[u8], max_chunk: usize) -> io::Result<Option<usize>> {
+    if let Some(newline) = buffer.iter().rposition(|&byte| byte == b'\n') {
+        return Ok(Some(newline + 1));
+    }
+    if buffer.len() < max_chunk {
+        return Ok(None);
+    }
+    if let Some(space) = buffer.iter().rposition(|&byte| byte == b' ') {
+        return Ok(Some(space + 1));
+    }
+
+    // a single word larger than the buffer, cut it at the last complete character.
+    match std::str::from_utf8(buffer) {
+        Ok(_) => Ok(Some(buffer.len())),
+        Err(error) if error.error_len().is_none() => Ok(Some(error.valid_up_to()).filter(|&cut| cut > 0)),
+        Err(error) => Err(io::Error::new(io::ErrorKind::InvalidData, error)),
+    }
+}
+
+fn write_translated<W: Write>(translator: &Translator, writer: &mut W, bytes: &[u8]) -> io::Result<()> {
+    let text = std::str::from_utf8(bytes).map_err(|error| io::Error::new(io::ErrorKind::InvalidData, error))?;
+
+    writer.write_all(translate_padded(text, translator).as_bytes())
+}
+
+#[cfg(test)]
+mod tests {
+    mod translate_stream {
+        use std::io::BufReader;
+
+        use crate::stream::{translate_stream, translate_stream_chunked, translate_stream_with};
+        use crate::Translator;
+
+        fn translate_with(input: &str, capacity: usize, max_chunk: usize) -> String {
+            let reader = BufReader::with_capacity(capacity, input.as_bytes());
+            let mut output = Vec::new();
+            translate_stream_chunked(&Translator::new().with_seed(1), reader, &mut output, max_chunk).unwrap();
+
+            String::from_utf8(output).unwrap()
+        }
+
+        #[test]
+        fn should_translate_line_by_line() {
+            let mut output = Vec::new();
+            let translator = Translator::new().with_seed(1);
+            translate_stream_with(&translator, "Rust\n\n  programmiert \n".as_bytes(), &mut output).unwrap();
+
+            assert_eq!(String::from_utf8(output).unwrap(), "Rusd\n\n  brogrammierd \n");
+        }
+
+        #[test]
+        fn should_not_split_words_or_characters() {
+            assert_eq!(translate_with("Öl Rust programmiert Österreich", 1, 8), "Öl Rusd brogrammierd Ösderreich");
+        }
+
+        #[test]
+        fn should_split_words_larger_than_the_buffer_at_character_boundaries() {
+            assert_eq!(translate_with("Ölölöl", 1, 3), "Ölölöl");
+        }
+
+        #[test]
+        fn should_reject_invalid_utf8() {
+            let mut output = Vec::new();
+
+            assert!(translate_stream(&[0xff, 0xfe, b'\n'][..], &mut output).is_err());
+        }
+
+        #[test]
+        fn should_translate_with_the_given_translator() {
+            let mut output = Vec::new();
+            let translator = Translator::new().with_profile("bavarian").unwrap().with_seed(1);
+            translate_stream_with(&translator, "ich\nich".as_bytes(), &mut output).unwrap();
+
+            assert_eq!(String::from_utf8(output).unwrap(), "i\ni");
+        }
+    }
+}
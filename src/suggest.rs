@@ -0,0 +1,191 @@
+//! Proposes new entries for a dictionary from a corpus.
+//!
+//! Words that come out of the translation pipeline the way they went in, apart from their case,
+//! are the ones nothing meddl-ish happened to. They are written as a unified diff adding them to the
+//! `translations` of the dictionary file, each with an empty pool for the reviewer to fill in.
+//! Until then `meddl lint` reports the empty pools as errors, so the patch can't be merged
+//! unreviewed by accident.
+//!
+//! # Example
+//!
+//! ```
+//! use meddl_translate::suggest::{patch, suggest};
+//! use meddl_translate::Dictionary;
+//!
+//! let json = "{\n  \"translations\": {\n    \"Hallo\": [\"Meddl\"]\n  }\n}\n";
+//! let dictionary = Dictionary::from_json(json).unwrap();
+//! let suggestions = suggest("Hallo Hund, Hund und Katze", &dictionary, 2);
+//!
+//! assert_eq!(
+//!     patch(json, "de.json", &suggestions).unwrap(),
+//!     "--- a/de.json
+//! +++ b/de.json
+//! @@ -3,2 +3,3 @@
+//! -    \"Hallo\": [\"Meddl\"]
+//! +    \"Hallo\": [\"Meddl\"],
+//! +    \"Hund\": []
+//!    }
+//! "
+//! );
+//! ```
+
+use std::path::Path;
+
+use crate::stats::{stats_with, WordCount};
+use crate::{Dictionary, Error};
+
+/// The untranslated words of `corpus` seen at least `min_count` times, most frequent first.
+pub fn suggest(corpus: &str, dictionary: &Dictionary, min_count: usize) -> Vec<WordCount> {
+    stats_with(corpus, dictionary)
+        .untranslated
+        .into_iter()
+        .filter(|word| word.count >= min_count && word.word.chars().all(char::is_alphabetic))
+        .collect()
+}
+
+/// A unified diff adding `suggestions` to the end of the `translations` of the dictionary
+/// `json` found at `path`, empty if there is nothing to suggest.
+///
+/// The rest of the file is left untouched, so the diff applies with `git apply` or `patch -p1`.
+/// Neither of them accepts absolute paths, so `path` has to be relative to where the patch is
+/// applied.
+pub fn patch(json: &str, path: &str, suggestions: &[WordCount]) -> Result<String, Error> {
+    if Path::new(path).is_absolute() {
+        return Err(Error::AbsolutePath(String::from(path)));
+    }
+    if suggestions.is_empty() {
+        return Ok(String::new());
+    }
+
+    let lines: Vec<&str> = json.lines().collect();
+    let (opening, closing) = translations_lines(&lines)?;
+    let indent = format!("{}  ", leading_whitespace(lines[closing]));
+    let entries: Vec<String> = suggestions
+        .iter()
+        .map(|suggestion| format!("{}{}: []", indent, serde_json::Value::from(suggestion.word.as_str())))
+        .collect();
+
+    let mut diff = format!("--- a/{}\n+++ b/{}\n", path, path);
+    let last = closing - 1;
+    // line numbers in hunk headers start at 1.
+    if last == opening {
+        diff.push_str(&format!("@@ -{},1 +{},{} @@\n", closing + 1, closing + 1, entries.len() + 1));
+    } else {
+        diff.push_str(&format!("@@ -{},2 +{},{} @@\n", last + 1, last + 1, entries.len() + 2));
+        diff.push_str(&format!("-{}\n+{},\n", lines[last], lines[last]));
+    }
+    diff.push_str(&format!("+{}\n", entries.join(",\n+")));
+    diff.push_str(&format!(" {}\n", lines[closing]));
+
+    Ok(diff)
+}
+
+/// Indices of the lines opening and closing the `translations` object.
+fn translations_lines(lines: &[&str]) -> Result<(usize, usize), Error> {
+    let opening = lines
+        .iter()
+        .position(|line| line.trim_start().starts_with("\"translations\""))
+        .ok_or_else(|| Error::InvalidDictionary(String::from("there is no translations section")))?;
+
+    let mut depth = 0;
+    let mut in_string = false;
+    let mut escaped = false;
+    for (index, line) in lines.iter().enumerate().skip(opening) {
+        for c in line.chars() {
+            match c {
+                _ if escaped => escaped = false,
+                '\\' if in_string => escaped = true,
+                '"' => in_string = !in_string,
+                '{' if !in_string => depth += 1,
+                '}' if !in_string => depth -= 1,
+                _ => {}
+            }
+        }
+
+        if depth == 0 {
+            if index == opening {
+                return Err(Error::InvalidDictionary(String::from(
+                    "translations have to be written on more than one line",
+                )));
+            }
+            return Ok((opening, index));
+        }
+    }
+
+    Err(Error::InvalidDictionary(String::from("translations are never closed")))
+}
+
+fn leading_whitespace(line: &str) -> &str {
+    &line[..line.len() - line.trim_start().len()]
+}
+
+#[cfg(test)]
+mod tests {
+    mod suggest {
+        use crate::suggest::suggest;
+        use crate::Dictionary;
+
+        #[test]
+        fn should_only_suggest_words_nothing_happened_to() {
+            let dictionary = Dictionary::from_json(r#"{"twistedChars": [["t", "d"]], "ignored": ["Rust"]}"#).unwrap();
+            let suggestions = suggest("Rust Hund Welt Hund 42", &dictionary, 1);
+
+            assert_eq!(suggestions.len(), 1);
+            assert_eq!(suggestions[0].word, "Hund");
+            assert_eq!(suggestions[0].count, 2);
+        }
+    }
+
+    mod patch {
+        use crate::stats::WordCount;
+        use crate::suggest::patch;
+
+        fn suggestions() -> Vec<WordCount> {
+            vec![
+                WordCount {
+                    word: String::from("Hund"),
+                    count: 2,
+                },
+                WordCount {
+                    word: String::from("sag \"was\""),
+                    count: 1,
+                },
+            ]
+        }
+
+        #[test]
+        fn should_fill_an_empty_section() {
+            let json = "{\n  \"version\": 2,\n  \"translations\": {\n  }\n}";
+
+            assert_eq!(
+                patch(json, "de.json", &suggestions()).unwrap(),
+                "--- a/de.json\n+++ b/de.json\n@@ -4,1 +4,3 @@\n+    \"Hund\": [],\n+    \"sag \\\"was\\\"\": []\n   }\n"
+            );
+        }
+
+        #[test]
+        fn should_skip_braces_in_strings() {
+            let json = "{\n  \"translations\": {\n    \"}\": [\n      \"{\"\n    ]\n  },\n  \"dot\": []\n}";
+            let diff = patch(json, "de.json", &suggestions()[..1]).unwrap();
+
+            assert!(diff.contains("@@ -5,2 +5,3 @@\n-    ]\n+    ],\n+    \"Hund\": []\n   },\n"));
+        }
+
+        #[test]
+        fn should_reject_absolute_paths() {
+            let json = "{\n  \"translations\": {\n  }\n}";
+
+            assert!(patch(json, "/root/dictionaries/de.json", &suggestions()[..1]).is_err());
+            assert!(patch(json, "dictionaries/de.json", &suggestions()[..1])
+                .unwrap()
+                .starts_with("--- a/dictionaries/de.json\n+++ b/dictionaries/de.json\n"));
+        }
+
+        #[test]
+        fn should_reject_single_line_translations() {
+            assert!(patch(r#"{"translations": {}}"#, "de.json", &suggestions()).is_err());
+            assert!(patch("{}", "de.json", &suggestions()).is_err());
+            assert_eq!(patch("{}", "de.json", &[]).unwrap(), "");
+        }
+    }
+}
@@ -0,0 +1,42 @@
+use std::ops::Range;
+
+/// What a [`Token`] stands for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum TokenKind {
+    /// A translated word.
+    Word,
+    /// A number, decimal, time or date, kept as it is or written in words.
+    Number,
+    /// Punctuation that was kept as it is, including dots that don't end a sentence.
+    Punctuation,
+    /// A space between two words.
+    Whitespace,
+    /// A suffix replacing the dot, exclamation mark or question mark ending a sentence.
+    Suffix,
+    /// An interlude inserted after a word, it has an empty span.
+    Interlude,
+    /// An opener, interjection or closer inserted by [`crate::Translator::with_fillers`], it
+    /// has an empty span.
+    Filler,
+}
+
+/// A piece of translated text together with the byte range of the original text it was made from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Token {
+    /// Byte range in the original text.
+    pub span: Range<usize>,
+    /// The translated text.
+    pub text: String,
+    pub kind: TokenKind,
+}
+
+impl Token {
+    pub(crate) fn new(span: Range<usize>, text: &str, kind: TokenKind) -> Token {
+        Token {
+            span,
+            text: String::from(text),
+            kind,
+        }
+    }
+}
@@ -0,0 +1,424 @@
+use std::ops::Range;
+use std::sync::{Arc, OnceLock};
+
+use rand::rngs::StdRng;
+use rand::{thread_rng, SeedableRng};
+
+use crate::{sentence, translate_chunk, Dictionary, Error, Step, Token, TokenKind};
+
+/// Minimum size of the pieces the input is split into, see [`Translator`].
+const CHUNK_SIZE: usize = 16 * 1024;
+
+/// Optional steps of the translation, all of them are off by default.
+#[derive(Debug, Clone, Copy, Default)]
+pub(crate) struct Options {
+    pub(crate) fillers: bool,
+    pub(crate) number_words: bool,
+    pub(crate) transliteration: bool,
+}
+
+/// A configurable translator.
+///
+/// Input is split into chunks of whole sentences, each translated on its own. Without a seed
+/// every chunk draws from the thread's random number generator. With a seed, every chunk gets
+/// a generator derived from the seed and the chunk's position, so the same input always gives
+/// the same result, no matter whether (or on how many threads) it was translated in parallel.
+///
+/// # Example
+///
+/// ```
+/// use meddl_translate::Translator;
+///
+/// let translator = Translator::new().with_seed(42);
+///
+/// assert_eq!(translator.translate("Hallo Welt!"), translator.translate("Hallo Welt!"));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Translator {
+    dictionary: Arc<Dictionary>,
+    seed: Option<u64>,
+    options: Options,
+}
+
+impl Default for Translator {
+    fn default() -> Translator {
+        Translator {
+            dictionary: Arc::new(Dictionary::builtin().clone()),
+            seed: None,
+            options: Options::default(),
+        }
+    }
+}
+
+impl Translator {
+    /// Creates a translator using the built-in dictionary.
+    pub fn new() -> Translator {
+        Translator::default()
+    }
+
+    /// Translates using `dictionary` instead of the built-in one.
+    pub fn with_dictionary(mut self, dictionary: Dictionary) -> Translator {
+        self.dictionary = Arc::new(dictionary);
+        self
+    }
+
+    /// Translates using the built-in dialect profile called `name`, see [`Dictionary::profiles`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meddl_translate::Translator;
+    ///
+    /// let translator = Translator::new().with_profile("swabian").unwrap().with_seed(1);
+    ///
+    /// assert_eq!(translator.translate("ich"), "i");
+    /// assert!(Translator::new().with_profile("klingon").is_err());
+    /// ```
+    pub fn with_profile(mut self, name: &str) -> Result<Translator, Error> {
+        let dictionary = Dictionary::profile(name).ok_or_else(|| Error::UnknownProfile(String::from(name)))?;
+        self.dictionary = Arc::new(dictionary.clone());
+
+        Ok(self)
+    }
+
+    /// Leaves `words` untranslated in addition to the `ignored` section of the dictionary.
+    ///
+    /// Entries are written like in the dictionary, so globs (`Meddl*`) and regular expressions
+    /// wrapped in slashes (`/^[A-Z]+$/`) work as well. Translators are cheap to clone, so this
+    /// can be used for a single call, e.g. to protect names that are only known at runtime.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meddl_translate::Translator;
+    ///
+    /// let translator = Translator::new().with_seed(1);
+    /// let protected = translator.clone().with_ignored(vec!["Rust", "/^[A-Z]{2,}$/"]).unwrap();
+    ///
+    /// assert_eq!(translator.translate("Rust"), "Rusd");
+    /// assert_eq!(protected.translate("Rust und HTTP"), "Rust und HTTP");
+    /// ```
+    pub fn with_ignored<I, S>(mut self, words: I) -> Result<Translator, Error>
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        let dictionary = Arc::make_mut(&mut self.dictionary);
+        dictionary.ignored.extend(words.into_iter().map(Into::into))?;
+        dictionary.transliterations = OnceLock::new();
+        dictionary.replacements = OnceLock::new();
+
+        Ok(self)
+    }
+
+    /// Inserts the openers, interjections and closers of the dictionary, each with the
+    /// probability given there.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meddl_translate::{Dictionary, Translator};
+    ///
+    /// let dictionary = Dictionary::from_json(
+    ///     r#"{"openers": {"probability": 1, "phrases": ["Meddl Leude!"]}, "dot": ["."]}"#,
+    /// ).unwrap();
+    /// let translator = Translator::new().with_dictionary(dictionary);
+    ///
+    /// assert_eq!(translator.translate("Hallo."), "Hallo.");
+    /// assert_eq!(translator.with_fillers().translate("Hallo."), "Meddl Leude! Hallo.");
+    /// ```
+    pub fn with_fillers(mut self) -> Translator {
+        self.options.fillers = true;
+        self
+    }
+
+    /// Writes whole numbers in words using the `numbers` section of the dictionary.
+    ///
+    /// Numbers are never translated like words, decimals, times and dates are always kept as
+    /// they are, including their punctuation.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meddl_translate::Translator;
+    ///
+    /// let translator = Translator::new().with_seed(1);
+    ///
+    /// assert_eq!(translator.translate("um 2 und 12:30"), "um 2 und 12:30");
+    /// assert_eq!(translator.with_number_words().translate("um 2 und 12:30"), "um zwaa und 12:30");
+    /// ```
+    pub fn with_number_words(mut self) -> Translator {
+        self.options.number_words = true;
+        self
+    }
+
+    /// Also finds translated and ignored words written with "ae", "oe", "ue" or "ss" instead
+    /// of umlauts and ß, like "grossen" for "großen".
+    ///
+    /// The translation is written the same way as the original, without umlauts.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meddl_translate::Translator;
+    ///
+    /// let translator = Translator::new().with_seed(1);
+    ///
+    /// // "großen" is ignored by the built-in dictionary.
+    /// assert_eq!(translator.translate("grossen"), "grossne");
+    /// assert_eq!(translator.with_transliteration().translate("grossen"), "grossen");
+    /// ```
+    pub fn with_transliteration(mut self) -> Translator {
+        self.options.transliteration = true;
+        self
+    }
+
+    /// Makes every translation deterministic.
+    pub fn with_seed(mut self, seed: u64) -> Translator {
+        self.seed = Some(seed);
+        self
+    }
+
+    /// Translates a string slice from German to Meddlfrängisch, see [`crate::translate`].
+    pub fn translate(&self, original: &str) -> String {
+        join_text(self.translate_tokens(original))
+    }
+
+    /// Translates a string slice and returns the tokens, see [`crate::translate_tokens`].
+    pub fn translate_tokens(&self, original: &str) -> Vec<Token> {
+        self.translate_observed(original, &mut |_| {})
+    }
+
+    /// Like [`Translator::translate_tokens`], calling `hook` with every step of the pipeline.
+    pub(crate) fn translate_observed(&self, original: &str, hook: &mut dyn FnMut(Step)) -> Vec<Token> {
+        let chunks = split_chunks(original, CHUNK_SIZE);
+        let translated = chunks
+            .iter()
+            .enumerate()
+            .map(|(index, chunk)| self.translate_range(original, index, chunk.clone(), hook))
+            .collect();
+
+        join_chunks(&chunks, translated)
+    }
+
+    /// Translates `original` and renders the translation as SSML, see [`crate::ssml`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meddl_translate::Translator;
+    ///
+    /// assert_eq!(Translator::new().with_seed(1).translate_ssml("Rust"), "<speak><s>Rusd</s></speak>");
+    /// ```
+    pub fn translate_ssml(&self, original: &str) -> String {
+        crate::ssml::render(original, &self.translate_tokens(original))
+    }
+
+    /// Scores how strongly `text` is already written in the dialect of the dictionary, see
+    /// [`crate::analysis`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meddl_translate::Translator;
+    ///
+    /// assert_eq!(Translator::new().analyze("Meddl Loide").score, 1.0);
+    /// ```
+    pub fn analyze(&self, text: &str) -> crate::analysis::Analysis {
+        crate::analysis::analyze_with(text, &self.dictionary)
+    }
+
+    /// Collects coverage statistics of the dictionary for `corpus`, see [`crate::stats`].
+    pub fn stats(&self, corpus: &str) -> crate::stats::Stats {
+        crate::stats::collect(self, &self.dictionary, corpus)
+    }
+
+    /// Translates `original` and transcribes the translation using the pronunciations of the
+    /// dictionary, see [`crate::phonetic`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meddl_translate::phonetic::Alphabet;
+    /// use meddl_translate::Translator;
+    ///
+    /// assert_eq!(Translator::new().transcribe("Rust", Alphabet::Ipa), "ʁʊst");
+    /// ```
+    #[cfg(feature = "phonetic")]
+    pub fn transcribe(&self, original: &str, alphabet: crate::phonetic::Alphabet) -> String {
+        crate::phonetic::transcribe_with(&self.translate(original), &self.dictionary, alphabet)
+    }
+
+    /// Like [`Translator::translate`], but translates the chunks of the input concurrently.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meddl_translate::Translator;
+    ///
+    /// let translator = Translator::new().with_seed(42);
+    ///
+    /// assert_eq!(translator.translate_parallel("Hallo Welt!"), translator.translate("Hallo Welt!"));
+    /// ```
+    #[cfg(feature = "parallel")]
+    pub fn translate_parallel(&self, original: &str) -> String {
+        join_text(self.translate_tokens_parallel(original))
+    }
+
+    /// Like [`Translator::translate_tokens`], but translates the chunks of the input concurrently.
+    #[cfg(feature = "parallel")]
+    pub fn translate_tokens_parallel(&self, original: &str) -> Vec<Token> {
+        use rayon::prelude::*;
+
+        let chunks = split_chunks(original, CHUNK_SIZE);
+        let translated = chunks
+            .par_iter()
+            .enumerate()
+            .map(|(index, chunk)| self.translate_range(original, index, chunk.clone(), &mut |_| {}))
+            .collect();
+
+        join_chunks(&chunks, translated)
+    }
+
+    fn translate_range(&self, original: &str, index: usize, range: Range<usize>, hook: &mut dyn FnMut(Step)) -> Vec<Token> {
+        let offset = range.start;
+        let chunk = &original[range];
+        let mut tokens = match self.seed {
+            Some(seed) => translate_chunk(chunk, &self.dictionary, self.options, &mut chunk_rng(seed, index), hook),
+            None => translate_chunk(chunk, &self.dictionary, self.options, &mut thread_rng(), hook),
+        };
+
+        for token in tokens.iter_mut() {
+            token.span = token.span.start + offset..token.span.end + offset;
+        }
+
+        tokens
+    }
+}
+
+fn chunk_rng(seed: u64, index: usize) -> StdRng {
+    StdRng::seed_from_u64(seed ^ (index as u64).wrapping_mul(0x9E37_79B9_7F4A_7C15))
+}
+
+/// Splits `text` into ranges of at least `min_size` bytes, cutting only at a single space that
+/// follows the end of a sentence. The space between two ranges belongs to neither of them.
+fn split_chunks(text: &str, min_size: usize) -> Vec<Range<usize>> {
+    let bytes = text.as_bytes();
+    let mut chunks = Vec::new();
+    let mut start = 0;
+    let ends_sentence = |index: usize| {
+        let word_start = text[..index].rfind(char::is_whitespace).map_or(0, |space| space + 1);
+        let next = text[index + 1..].split_whitespace().next();
+
+        sentence::ends_sentence(&text[word_start..index], next)
+    };
+
+    for (index, &byte) in bytes.iter().enumerate() {
+        let is_single_space = byte == b' ' && bytes.get(index + 1).is_some_and(|&next| next != b' ');
+
+        if is_single_space && index - start >= min_size && ends_sentence(index) {
+            chunks.push(start..index);
+            start = index + 1;
+        }
+    }
+    chunks.push(start..text.len());
+
+    chunks
+}
+
+fn join_chunks(chunks: &[Range<usize>], translated: Vec<Vec<Token>>) -> Vec<Token> {
+    let mut tokens = Vec::new();
+
+    for (index, (chunk, chunk_tokens)) in chunks.iter().zip(translated).enumerate() {
+        if index > 0 {
+            let space = chunk.start - 1;
+            tokens.push(Token::new(space..chunk.start, " ", TokenKind::Whitespace));
+        }
+        tokens.extend(chunk_tokens);
+    }
+
+    tokens
+}
+
+fn join_text(tokens: Vec<Token>) -> String {
+    tokens.into_iter().map(|token| token.text).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    mod split_chunks {
+        use crate::translator::split_chunks;
+
+        #[test]
+        fn should_split_after_sentences_only() {
+            assert_eq!(split_chunks("Eins. Zwei! Drei? Vier", 1), vec![0..5, 6..11, 12..17, 18..22]);
+        }
+
+        #[test]
+        fn should_not_split_after_abbreviations() {
+            assert_eq!(split_chunks("Eins usw. Zwei. Drei", 1), vec![0..15, 16..20]);
+        }
+
+        #[test]
+        fn should_respect_min_size() {
+            assert_eq!(split_chunks("Eins. Zwei. Drei.", 8), vec![0..11, 12..17]);
+        }
+
+        #[test]
+        fn should_not_split_at_multiple_spaces() {
+            assert_eq!(split_chunks("Eins.  Zwei.", 1), vec![0..12]);
+        }
+    }
+
+    mod translate {
+        use crate::translator::{join_chunks, split_chunks};
+        use crate::{Translator, TokenKind};
+
+        #[test]
+        fn should_be_deterministic_with_seed() {
+            let text = "Hallo Welt! Wie geht es dir? Rainer ist ein Drachenlord.";
+            let translator = Translator::new().with_seed(7);
+
+            assert_eq!(translator.translate(text), translator.translate(text));
+        }
+
+        #[test]
+        fn should_join_chunks_with_whitespace() {
+            let translator = Translator::new().with_seed(1);
+            let text = "Rust. Rust";
+            let chunks = split_chunks(text, 1);
+            let translated = chunks
+                .iter()
+                .enumerate()
+                .map(|(index, chunk)| translator.translate_range(text, index, chunk.clone(), &mut |_| {}))
+                .collect();
+            let tokens = join_chunks(&chunks, translated);
+
+            assert_eq!(tokens.last().unwrap().span, 6..10);
+            assert_eq!(tokens[tokens.len() - 2].kind, TokenKind::Whitespace);
+            assert_eq!(tokens[tokens.len() - 2].span, 5..6);
+        }
+    }
+
+    #[cfg(feature = "parallel")]
+    mod translate_parallel {
+        use crate::Translator;
+
+        #[test]
+        fn should_not_depend_on_thread_count() {
+            let text = "Hallo Welt! Wie geht es dir? Rainer ist ein Drachenlord. ".repeat(2000);
+            let translator = Translator::new().with_seed(42);
+            let translate_on = |threads| {
+                rayon::ThreadPoolBuilder::new()
+                    .num_threads(threads)
+                    .build()
+                    .unwrap()
+                    .install(|| translator.translate_parallel(&text))
+            };
+
+            let single = translate_on(1);
+            assert_eq!(single, translate_on(4));
+            assert_eq!(single, translator.translate(&text));
+        }
+    }
+}
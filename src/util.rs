@@ -1,47 +1,32 @@
-use serde_json::Value;
-use rand::Rng;
+use rand::{Rng, RngCore};
 
-pub(crate) fn is_ignored_word(word: &str, translation: &Value) -> bool {
-    let ignored_words = translation["ignored"]
-        .as_array()
-        .unwrap();
-
-    let word = serde_json::to_value(
-        word
-    )
-        .unwrap();
-    if ignored_words.contains(&word) {
-        return true;
-    }
-
-    false
-}
-
-pub(crate) fn get_random_index(vec: &Vec<Value>) -> usize {
+pub(crate) fn get_random_index<T>(vec: &[T], rng: &mut dyn RngCore) -> usize {
     let len = vec.len();
-    rand::thread_rng().gen_range(0..len)
+    rng.gen_range(0..len)
 }
 
 pub(crate) fn capitalize_word(word: &str) -> String {
-    let mut chars = word.chars();
-    let capitalized: String = chars
-        .next()
-        .unwrap()
-        .to_uppercase()
-        .collect::<Vec<char>>()
-        .iter()
-        .collect();
-    let index = match &capitalized.len() {
-        1 => 1,
-        2 => 2,
-        _ => 1
+    let first_length = match word.chars().next() {
+        Some(first) => first.len_utf8(),
+        None => return String::new(),
     };
-    return capitalized + word.split_at(index).1;
+    let (first, rest) = word.split_at(first_length);
+
+    first.to_uppercase() + rest
+}
+
+pub(crate) fn lowercase_first(word: &str) -> String {
+    let mut chars = word.chars();
+
+    match chars.next() {
+        Some(first) => first.to_lowercase().chain(chars).collect(),
+        None => String::new(),
+    }
 }
 
 #[cfg(feature = "interlude")]
-pub(crate) fn is_one_percent_chance() -> bool {
-    let random = rand::thread_rng().gen_range(0..100);
+pub(crate) fn is_one_percent_chance(rng: &mut dyn RngCore) -> bool {
+    let random = rng.gen_range(0..100);
 
     if random == 1 {
         return true;
@@ -69,5 +54,13 @@ mod tests {
         fn should_capitalize_correctly() {
             assert_eq!(capitalize_word("doppelhaushälfte"), "Doppelhaushälfte");
         }
+
+        #[test]
+        fn should_keep_words_starting_with_symbols() {
+            assert_eq!(capitalize_word("„hallo"), "„hallo");
+            assert_eq!(capitalize_word("€"), "€");
+            assert_eq!(capitalize_word("😀 welt"), "😀 welt");
+            assert_eq!(capitalize_word(""), "");
+        }
     }
 }
\ No newline at end of file
@@ -0,0 +1,83 @@
+//! Compiles `tests/ffi/translate.c` against the C library and runs it.
+
+#![cfg(feature = "ffi")]
+
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use meddl_translate::Translator;
+
+/// Builds the C library the way the README describes it and returns the directory it was
+/// built into. It's built with the same features as this test, so its translations match
+/// the ones of [`Translator`].
+fn build_library() -> PathBuf {
+    let target = Path::new(env!("CARGO_TARGET_TMPDIR")).join("ffi");
+
+    let mut cargo = Command::new(env::var_os("CARGO").unwrap_or_else(|| "cargo".into()));
+    cargo.args(["build", "--quiet", "--package", "meddl_translate_ffi"]);
+    if cfg!(feature = "interlude") {
+        cargo.args(["--features", "interlude"]);
+    }
+    let built = cargo
+        .arg("--manifest-path")
+        .arg(Path::new(env!("CARGO_MANIFEST_DIR")).join("Cargo.toml"))
+        .arg("--target-dir")
+        .arg(&target)
+        .status()
+        .expect("Could not run cargo.");
+    assert!(built.success(), "could not build the C library");
+
+    target.join("debug")
+}
+
+/// The header in `include` has to match the one generated by the build script. Run with
+/// `UPDATE_GOLDEN=1` to rewrite it.
+#[test]
+fn should_ship_the_generated_header() {
+    let shipped = Path::new(env!("CARGO_MANIFEST_DIR")).join("include/meddl_translate.h");
+    let generated = fs::read_to_string(Path::new(env!("OUT_DIR")).join("meddl_translate.h")).unwrap();
+
+    if env::var_os("UPDATE_GOLDEN").is_some() {
+        fs::write(&shipped, &generated).unwrap();
+    }
+    assert_eq!(fs::read_to_string(&shipped).unwrap(), generated, "include/meddl_translate.h is outdated");
+}
+
+#[test]
+fn should_translate_from_c() {
+    let manifest = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let library = build_library();
+    let program = Path::new(env!("CARGO_TARGET_TMPDIR")).join("translate");
+
+    let compiled = Command::new(env::var_os("CC").unwrap_or_else(|| "cc".into()))
+        .arg(manifest.join("tests/ffi/translate.c"))
+        .arg("-I")
+        .arg(manifest.join("include"))
+        .arg("-L")
+        .arg(&library)
+        .arg(format!("-Wl,-rpath,{}", library.display()))
+        .arg("-lmeddl_translate_ffi")
+        .arg("-o")
+        .arg(&program)
+        .status()
+        .expect("Could not run the C compiler.");
+    assert!(compiled.success(), "could not compile tests/ffi/translate.c");
+
+    let inputs = ["Hallo Welt!", "Spaß mit Rust."];
+    // cargo points `LD_LIBRARY_PATH` at its own target directory, which would win over the rpath.
+    let output = Command::new(&program)
+        .args(inputs)
+        .env("LD_LIBRARY_PATH", &library)
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+
+    let translator = Translator::new().with_seed(42);
+    let expected: String = inputs
+        .iter()
+        .map(|input| format!("{}\n", translator.translate(input)))
+        .collect();
+    assert_eq!(String::from_utf8(output.stdout).unwrap(), expected);
+}
@@ -0,0 +1,59 @@
+/* Exercises the C API, run by tests/ffi.rs. Prints the seeded translation of every argument,
+ * one per line, and fails if an error isn't reported the way it should be. */
+
+#include <stdio.h>
+#include <string.h>
+
+#include "meddl_translate.h"
+
+static int expect_error(const void *result, const char *what) {
+    const char *error = meddl_last_error();
+
+    if (result != NULL || error == NULL || strlen(error) == 0) {
+        fprintf(stderr, "%s should have failed with a message\n", what);
+        return 1;
+    }
+    return 0;
+}
+
+int main(int argc, char **argv) {
+    int failures = 0;
+    MeddlTranslator *translator = meddl_translator_new();
+
+    if (!meddl_translator_set_seed(translator, 42)) {
+        fprintf(stderr, "could not set the seed: %s\n", meddl_last_error());
+        return 1;
+    }
+
+    for (int i = 1; i < argc; i++) {
+        char *translated = meddl_translate(translator, argv[i]);
+        if (translated == NULL) {
+            fprintf(stderr, "could not translate \"%s\": %s\n", argv[i], meddl_last_error());
+            failures++;
+            continue;
+        }
+        printf("%s\n", translated);
+        meddl_string_free(translated);
+    }
+
+    failures += expect_error(meddl_translator_with_profile("klingon"), "an unknown profile");
+    failures += expect_error(meddl_translator_with_dictionary("{\"rules\": 1}"), "an invalid dictionary");
+    failures += expect_error(meddl_translate(translator, NULL), "translating NULL");
+    failures += expect_error(meddl_translate(translator, "\xff"), "translating invalid UTF-8");
+
+    MeddlTranslator *swabian = meddl_translator_with_profile("swabian");
+    meddl_translator_set_seed(swabian, 42);
+    char *translated = meddl_translate(swabian, "ich");
+    if (translated == NULL || strcmp(translated, "i") != 0) {
+        fprintf(stderr, "the swabian profile should translate \"ich\" to \"i\"\n");
+        failures++;
+    }
+
+    meddl_string_free(translated);
+    meddl_translator_free(swabian);
+    meddl_translator_free(translator);
+    meddl_string_free(NULL);
+    meddl_translator_free(NULL);
+
+    return failures == 0 ? 0 : 1;
+}